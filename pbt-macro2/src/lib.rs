@@ -3,7 +3,11 @@
 use {
     proc_macro2::TokenStream,
     quote::quote,
-    syn::{Data, DeriveInput, Expr, Fields, FnArg, ItemFn, LitInt, Pat, ReturnType, Type},
+    syn::{
+        Data, DeriveInput, Expr, ExprLit, ExprRange, ExprUnary, Fields, FnArg, ItemFn, Lit, LitInt,
+        LitStr, Pat, RangeLimits, ReturnType, Token, Type, UnOp, WherePredicate,
+        punctuated::Punctuated,
+    },
 };
 
 /// Derive `::pbt::Pbt` for an arbitrary type.
@@ -30,9 +34,131 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
         deconstruction: TokenStream,
         field_pushes: Vec<TokenStream>,
         field_type_inserts: Vec<TokenStream>,
+        range_checks: Vec<TokenStream>,
         span: proc_macro2::Span,
     }
 
+    /// How a field is stored: as itself, or,
+    /// if it has a `#[pbt(range = "...")]` attribute, as a `Bounded` integer.
+    enum Stored<'field> {
+        Bounded {
+            bounded: TokenStream,
+            /// A `const` item that fails to compile unless the range fits in the field's type.
+            check: TokenStream,
+        },
+        Itself(&'field Type),
+    }
+
+    impl<'field> Stored<'field> {
+        fn construction(&self) -> TokenStream {
+            match *self {
+                Self::Bounded { ref bounded, .. } => {
+                    quote! { ::pbt::range::narrow(fields.field::<#bounded>()) }
+                }
+                Self::Itself(_) => quote! { fields.field() },
+            }
+        }
+
+        fn of(field: &'field syn::Field) -> syn::Result<Self> {
+            let mut stored = Self::Itself(&field.ty);
+            for attr in &field.attrs {
+                if !attr.path().is_ident("pbt") {
+                    continue;
+                }
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("range") {
+                        let range: LitStr = meta.value()?.parse()?;
+                        let (lo, hi) = range_bounds(&range.parse()?)?;
+                        let ty = &field.ty;
+                        stored = Self::Bounded {
+                            bounded: quote! { ::pbt::bounded::Bounded<{ #lo }, { #hi }> },
+                            check: quote! {
+                                const _: () = ::pbt::range::check::<#ty, { #lo }, { #hi }>();
+                            },
+                        };
+                        Ok(())
+                    } else {
+                        Err(meta.error("unrecognized `#[pbt(...)]` field attribute"))
+                    }
+                })?;
+            }
+            Ok(stored)
+        }
+
+        fn push(&self, binding: &proc_macro2::Ident) -> TokenStream {
+            match *self {
+                Self::Bounded { ref bounded, .. } => quote! {
+                    let () = acc.push::<#bounded>(::pbt::range::widen(#binding));
+                },
+                Self::Itself(_) => quote! {
+                    let () = acc.push(#binding);
+                },
+            }
+        }
+
+        fn range_check(&self) -> Option<TokenStream> {
+            match *self {
+                Self::Bounded { ref check, .. } => Some(check.clone()),
+                Self::Itself(_) => None,
+            }
+        }
+
+        fn type_insert(&self) -> TokenStream {
+            let ty = match *self {
+                Self::Bounded { ref bounded, .. } => bounded.clone(),
+                Self::Itself(ty) => quote! { #ty },
+            };
+            quote! {
+                let () = registration.register::<#ty>();
+                let () = acc.insert(::core::any::TypeId::of::<#ty>());
+            }
+        }
+    }
+
+    /// Parse an integer literal, possibly negated.
+    fn int(expr: &Expr) -> syn::Result<i64> {
+        match *expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(ref lit),
+                ..
+            }) => lit.base10_parse(),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr: ref negated,
+                ..
+            }) => int(negated)?
+                .checked_neg()
+                .ok_or_else(|| syn::Error::new_spanned(expr, "out of range for `i64`")),
+            _ => Err(syn::Error::new_spanned(expr, "expected an integer literal")),
+        }
+    }
+
+    /// The inclusive bounds of a range like `0..=100` or `-5..5`.
+    fn range_bounds(
+        range: &ExprRange,
+    ) -> syn::Result<(proc_macro2::Literal, proc_macro2::Literal)> {
+        let (&Some(ref start), &Some(ref end)) = (&range.start, &range.end) else {
+            return Err(syn::Error::new_spanned(
+                range,
+                "`#[pbt(range = ...)]` requires both a start and an end",
+            ));
+        };
+        let lo = int(start)?;
+        let hi = match range.limits {
+            RangeLimits::Closed(_) => int(end)?,
+            RangeLimits::HalfOpen(_) => int(end)?
+                .checked_sub(1)
+                .ok_or_else(|| syn::Error::new_spanned(end, "out of range for `i64`"))?,
+        };
+        if lo > hi {
+            return Err(syn::Error::new_spanned(range, "empty range"));
+        }
+        Ok((
+            proc_macro2::Literal::i64_unsuffixed(lo),
+            proc_macro2::Literal::i64_unsuffixed(hi),
+        ))
+    }
+
     fn pattern(
         head: TokenStream,
         fields: &Fields,
@@ -44,6 +170,7 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
                 deconstruction: head,
                 field_pushes: Vec::new(),
                 field_type_inserts: Vec::new(),
+                range_checks: Vec::new(),
                 span,
             }),
             Fields::Unnamed(ref unnamed_fields) => {
@@ -51,54 +178,50 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
                 let mut field_constructions = Vec::new();
                 let mut field_pushes = Vec::new();
                 let mut field_type_inserts = Vec::new();
+                let mut range_checks = Vec::new();
                 for (index, field) in unnamed_fields.unnamed.iter().enumerate() {
                     let field_binding = quote::format_ident!("_anonymous_{index}");
-                    field_constructions.push(quote! { fields.field() });
-                    let ty = &field.ty;
-                    field_type_inserts.push(quote! {
-                        let () = registration.register::<#ty>();
-                        let () = acc.insert(::core::any::TypeId::of::<#ty>());
-                    });
+                    let stored = Stored::of(field)?;
+                    field_constructions.push(stored.construction());
+                    field_type_inserts.push(stored.type_insert());
+                    range_checks.extend(stored.range_check());
+                    field_pushes.push(stored.push(&field_binding));
                     field_bindings.push(field_binding);
                 }
-                for field_binding in field_bindings.iter().rev() {
-                    field_pushes.push(quote! {
-                        let () = acc.push(#field_binding);
-                    });
-                }
+                let () = field_pushes.reverse();
                 Ok(Pattern {
                     construction: quote! { #head(#(#field_constructions),*) },
                     deconstruction: quote! { #head(#(#field_bindings),*) },
                     field_pushes,
                     field_type_inserts,
+                    range_checks,
                     span,
                 })
             }
             Fields::Named(ref named_fields) => {
                 let mut field_bindings = Vec::new();
+                let mut field_constructions = Vec::new();
                 let mut field_pushes = Vec::new();
                 let mut field_type_inserts = Vec::new();
+                let mut range_checks = Vec::new();
                 for field in &named_fields.named {
                     let Some(field_binding) = field.ident.clone() else {
                         return Err(syn::Error::new_spanned(field, "missing field name"));
                     };
-                    let ty = &field.ty;
-                    field_type_inserts.push(quote! {
-                        let () = registration.register::<#ty>();
-                        let () = acc.insert(::core::any::TypeId::of::<#ty>());
-                    });
+                    let stored = Stored::of(field)?;
+                    field_constructions.push(stored.construction());
+                    field_type_inserts.push(stored.type_insert());
+                    range_checks.extend(stored.range_check());
+                    field_pushes.push(stored.push(&field_binding));
                     field_bindings.push(field_binding);
                 }
-                for field_binding in field_bindings.iter().rev() {
-                    field_pushes.push(quote! {
-                        let () = acc.push(#field_binding);
-                    });
-                }
+                let () = field_pushes.reverse();
                 Ok(Pattern {
-                    construction: quote! { #head { #(#field_bindings: fields.field()),* } },
+                    construction: quote! { #head { #(#field_bindings: #field_constructions),* } },
                     deconstruction: quote! { #head { #(#field_bindings),* } },
                     field_pushes,
                     field_type_inserts,
+                    range_checks,
                     span,
                 })
             }
@@ -106,11 +229,27 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
     }
 
     let DeriveInput {
+        attrs,
         data: input_data,
         generics,
         ident,
         ..
     } = syn::parse2(ts)?;
+    let mut custom_bounds: Option<Punctuated<WherePredicate, Token![,]>> = None;
+    for attr in &attrs {
+        if !attr.path().is_ident("pbt") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                let bounds: LitStr = meta.value()?.parse()?;
+                custom_bounds = Some(bounds.parse_with(Punctuated::parse_terminated)?);
+                Ok(())
+            } else {
+                Err(meta.error("unrecognized `#[pbt(...)]` attribute"))
+            }
+        })?;
+    }
     let patterns = match input_data {
         Data::Enum(enum_data) => enum_data
             .variants
@@ -139,14 +278,27 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
     let bad_variant_message =
         format!("can't instantiate variant #{{algebraic_index}} of `{ident}`");
     let mut bounded_generics = generics;
-    for parameter in bounded_generics.type_params_mut() {
-        parameter.bounds.push(syn::parse_quote! { ::pbt::Pbt });
+    for lifetime in bounded_generics.lifetimes_mut() {
+        // `Pbt: 'static`, so every borrow must be `'static` as well:
+        lifetime.bounds.push(syn::parse_quote! { 'static });
+    }
+    if let Some(bounds) = custom_bounds {
+        // Replace, rather than extend, the automatic bounds below:
+        bounded_generics
+            .make_where_clause()
+            .predicates
+            .extend(bounds);
+    } else {
+        for parameter in bounded_generics.type_params_mut() {
+            parameter.bounds.push(syn::parse_quote! { ::pbt::Pbt });
+        }
     }
     let (impl_generics, ty_generics, where_clause) = bounded_generics.split_for_impl();
 
     let mut construct_arms = Vec::new();
     let mut deconstruct_arms = Vec::new();
     let mut register_pushes = Vec::new();
+    let range_checks = patterns.iter().flat_map(|pattern| &pattern.range_checks);
     for (zero_index, pattern) in patterns.iter().enumerate() {
         let one_index = zero_index
             .checked_add(1)
@@ -215,6 +367,8 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
                 ::pbt::reflection::Variants::Algebraic(acc)
             }
         }
+
+        #(#range_checks)*
     })
 }
 
@@ -225,7 +379,7 @@ pub fn try_derive_pbt(ts: TokenStream) -> syn::Result<TokenStream> {
 /// If the input is not up to the task.
 #[inline]
 pub fn try_pbt_with_cases(ts: TokenStream, n_cases: Option<Expr>) -> syn::Result<TokenStream> {
-    let n_cases_expr = n_cases.unwrap_or_else(|| Expr::Verbatim(quote! { ::pbt::DEFAULT_N_CASES }));
+    let n_cases_expr = n_cases.unwrap_or_else(|| Expr::Verbatim(quote! { ::pbt::n_cases() }));
     let ItemFn {
         attrs, block, sig, ..
     } = syn::parse2(ts)?;
@@ -332,7 +486,8 @@ pub fn try_pbt_with_cases(ts: TokenStream, n_cases: Option<Expr>) -> syn::Result
         #[test]
         #(#attrs)*
         fn #ident() {
-            let mut prng = ::pbt::WyRand::new(::pbt::getrandom());
+            let seed = ::pbt::seed();
+            let mut prng = ::pbt::WyRand::new(seed);
             let maybe_witness = pbt::witness(
                 |#pat: #ty| -> Option<Option<String>> {
                     ::pbt::panic::catch(move || #block).err()
@@ -343,11 +498,11 @@ pub fn try_pbt_with_cases(ts: TokenStream, n_cases: Option<Expr>) -> syn::Result
             if let Some((witness, maybe_panic_msg)) = maybe_witness {
                 if let Some(panic_msg) = maybe_panic_msg {
                     panic!(
-                        "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}",
+                        "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
                     );
                 } else {
                     panic!(
-                        "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.",
+                        "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
                     );
                 }
             }
@@ -713,6 +868,260 @@ impl<A: ::pbt::Pbt, B: ::pbt::Pbt, C: ::pbt::Pbt> ::pbt::Pbt for Generic<A, B, C
         );
     }
 
+    #[test]
+    fn mixed_generics() {
+        expect_test(
+            r#"
+struct Mixed<'a, T, const N: usize>(PhantomData<&'a ()>, [T; N]);
+"#,
+            derive_pbt,
+            r#"
+impl<'a: 'static, T: ::pbt::Pbt, const N: usize> ::pbt::Pbt for Mixed<'a, T, N> {
+    #[inline]
+    fn construct<F>(
+        ::pbt::reflection::Parts {
+            mut fields,
+            variant_index,
+        }: ::pbt::reflection::Parts<F>,
+    ) -> Self
+    where
+        F: ::pbt::fields::Fields,
+    {
+        let algebraic_index: usize = variant_index
+            .expect("`Mixed` is not a literal")
+            .get();
+        match algebraic_index {
+            1 => Self(fields.field(), fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Mixed`"),
+        }
+    }
+    #[inline]
+    fn deconstruct(self) -> ::pbt::reflection::Parts<::pbt::fields::Store> {
+        match self {
+            Self(_anonymous_0, _anonymous_1) => {
+                ::pbt::reflection::Parts {
+                    fields: {
+                        let mut acc = ::pbt::fields::Store::new();
+                        let () = acc.push(_anonymous_1);
+                        let () = acc.push(_anonymous_0);
+                        acc
+                    },
+                    variant_index: Some(const { ::core::num::NonZero::new(1).unwrap() }),
+                }
+            }
+        }
+    }
+    #[inline]
+    fn register(
+        registration: &mut ::pbt::registration::Registration<'_>,
+    ) -> ::pbt::reflection::Variants<Self> {
+        let mut acc = vec![];
+        let () = acc
+            .push(::pbt::reflection::Variant {
+                field_types: {
+                    let mut acc = ::pbt::multiset::Multiset::new();
+                    let () = registration.register::<PhantomData<&'a ()>>();
+                    let () = acc
+                        .insert(::core::any::TypeId::of::<PhantomData<&'a ()>>());
+                    let () = registration.register::<[T; N]>();
+                    let () = acc.insert(::core::any::TypeId::of::<[T; N]>());
+                    acc
+                },
+            });
+        ::pbt::reflection::Variants::Algebraic(acc)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn custom_bound() {
+        expect_test(
+            r#"
+#[pbt(bound = "T: 'static + Clone + core::fmt::Debug")]
+struct Tagged<T>(PhantomData<T>);
+"#,
+            derive_pbt,
+            r#"
+impl<T> ::pbt::Pbt for Tagged<T>
+where
+    T: 'static + Clone + core::fmt::Debug,
+{
+    #[inline]
+    fn construct<F>(
+        ::pbt::reflection::Parts {
+            mut fields,
+            variant_index,
+        }: ::pbt::reflection::Parts<F>,
+    ) -> Self
+    where
+        F: ::pbt::fields::Fields,
+    {
+        let algebraic_index: usize = variant_index
+            .expect("`Tagged` is not a literal")
+            .get();
+        match algebraic_index {
+            1 => Self(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Tagged`"),
+        }
+    }
+    #[inline]
+    fn deconstruct(self) -> ::pbt::reflection::Parts<::pbt::fields::Store> {
+        match self {
+            Self(_anonymous_0) => {
+                ::pbt::reflection::Parts {
+                    fields: {
+                        let mut acc = ::pbt::fields::Store::new();
+                        let () = acc.push(_anonymous_0);
+                        acc
+                    },
+                    variant_index: Some(const { ::core::num::NonZero::new(1).unwrap() }),
+                }
+            }
+        }
+    }
+    #[inline]
+    fn register(
+        registration: &mut ::pbt::registration::Registration<'_>,
+    ) -> ::pbt::reflection::Variants<Self> {
+        let mut acc = vec![];
+        let () = acc
+            .push(::pbt::reflection::Variant {
+                field_types: {
+                    let mut acc = ::pbt::multiset::Multiset::new();
+                    let () = registration.register::<PhantomData<T>>();
+                    let () = acc.insert(::core::any::TypeId::of::<PhantomData<T>>());
+                    acc
+                },
+            });
+        ::pbt::reflection::Variants::Algebraic(acc)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ranged_field() {
+        expect_test(
+            r#"
+struct Percent {
+    #[pbt(range = "0..=100")]
+    value: u8,
+    offset: u16,
+}
+"#,
+            derive_pbt,
+            r#"
+impl ::pbt::Pbt for Percent {
+    #[inline]
+    fn construct<F>(
+        ::pbt::reflection::Parts {
+            mut fields,
+            variant_index,
+        }: ::pbt::reflection::Parts<F>,
+    ) -> Self
+    where
+        F: ::pbt::fields::Fields,
+    {
+        let algebraic_index: usize = variant_index
+            .expect("`Percent` is not a literal")
+            .get();
+        match algebraic_index {
+            1 => {
+                Self {
+                    value: ::pbt::range::narrow(
+                        fields.field::<::pbt::bounded::Bounded<{ 0 }, { 100 }>>(),
+                    ),
+                    offset: fields.field(),
+                }
+            }
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Percent`"),
+        }
+    }
+    #[inline]
+    fn deconstruct(self) -> ::pbt::reflection::Parts<::pbt::fields::Store> {
+        match self {
+            Self { value, offset } => {
+                ::pbt::reflection::Parts {
+                    fields: {
+                        let mut acc = ::pbt::fields::Store::new();
+                        let () = acc.push(offset);
+                        let () = acc
+                            .push::<
+                                ::pbt::bounded::Bounded<{ 0 }, { 100 }>,
+                            >(::pbt::range::widen(value));
+                        acc
+                    },
+                    variant_index: Some(const { ::core::num::NonZero::new(1).unwrap() }),
+                }
+            }
+        }
+    }
+    #[inline]
+    fn register(
+        registration: &mut ::pbt::registration::Registration<'_>,
+    ) -> ::pbt::reflection::Variants<Self> {
+        let mut acc = vec![];
+        let () = acc
+            .push(::pbt::reflection::Variant {
+                field_types: {
+                    let mut acc = ::pbt::multiset::Multiset::new();
+                    let () = registration
+                        .register::<::pbt::bounded::Bounded<{ 0 }, { 100 }>>();
+                    let () = acc
+                        .insert(
+                            ::core::any::TypeId::of::<
+                                ::pbt::bounded::Bounded<{ 0 }, { 100 }>,
+                            >(),
+                        );
+                    let () = registration.register::<u16>();
+                    let () = acc.insert(::core::any::TypeId::of::<u16>());
+                    acc
+                },
+            });
+        ::pbt::reflection::Variants::Algebraic(acc)
+    }
+}
+const _: () = ::pbt::range::check::<u8, { 0 }, { 100 }>();
+"#,
+        );
+    }
+
+    #[test]
+    fn empty_range() {
+        assert_eq!(
+            try_derive_pbt(
+                r#"
+struct Empty(#[pbt(range = "5..5")] u8);
+"#
+                .parse()
+                .expect("input couldn't be parsed"),
+            )
+            .expect_err("empty ranges ought to be rejected")
+            .to_string(),
+            "empty range",
+        );
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_eq!(
+            try_derive_pbt(
+                r#"
+#[pbt(bounds = "T: Clone")]
+struct Tagged<T>(PhantomData<T>);
+"#
+                .parse()
+                .expect("input couldn't be parsed"),
+            )
+            .expect_err("unrecognized attributes ought to be rejected")
+            .to_string(),
+            "unrecognized `#[pbt(...)]` attribute",
+        );
+    }
+
     #[test]
     fn at_least_42() {
         expect_test(
@@ -727,7 +1136,8 @@ fn less_than_42(lc: &LambdaCalculus) {
             r#"
 #[test]
 fn less_than_42() {
-    let mut prng = ::pbt::WyRand::new(::pbt::getrandom());
+    let seed = ::pbt::seed();
+    let mut prng = ::pbt::WyRand::new(seed);
     let maybe_witness = pbt::witness(
         |lc: &LambdaCalculus| -> Option<Option<String>> {
             ::pbt::panic::catch(move || {
@@ -743,11 +1153,51 @@ fn less_than_42() {
     if let Some((witness, maybe_panic_msg)) = maybe_witness {
         if let Some(panic_msg) = maybe_panic_msg {
             panic!(
-                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}",
+                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
+            );
+        } else {
+            panic!(
+                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
+            );
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn env_defaults() {
+        expect_test(
+            r#"
+fn env_defaults(lhs: &usize, rhs: &usize) {
+    assert!(*lhs <= *rhs);
+}
+"#,
+            |ts| pbt(ts, TokenStream::new()),
+            r#"
+#[test]
+fn env_defaults() {
+    let seed = ::pbt::seed();
+    let mut prng = ::pbt::WyRand::new(seed);
+    let maybe_witness = pbt::witness(
+        |&(ref lhs, ref rhs): &(usize, usize)| -> Option<Option<String>> {
+            ::pbt::panic::catch(move || {
+                    assert!(* lhs <= * rhs);
+                })
+                .err()
+        },
+        ::pbt::n_cases(),
+        &mut prng,
+    );
+    if let Some((witness, maybe_panic_msg)) = maybe_witness {
+        if let Some(panic_msg) = maybe_panic_msg {
+            panic!(
+                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
             );
         } else {
             panic!(
-                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.",
+                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
             );
         }
     }
@@ -768,7 +1218,8 @@ fn lhs_at_most_rhs(lhs: &usize, rhs: &usize) {
             r#"
 #[test]
 fn lhs_at_most_rhs() {
-    let mut prng = ::pbt::WyRand::new(::pbt::getrandom());
+    let seed = ::pbt::seed();
+    let mut prng = ::pbt::WyRand::new(seed);
     let maybe_witness = pbt::witness(
         |&(ref lhs, ref rhs): &(usize, usize)| -> Option<Option<String>> {
             ::pbt::panic::catch(move || {
@@ -782,11 +1233,11 @@ fn lhs_at_most_rhs() {
     if let Some((witness, maybe_panic_msg)) = maybe_witness {
         if let Some(panic_msg) = maybe_panic_msg {
             panic!(
-                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}",
+                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\n{panic_msg}\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
             );
         } else {
             panic!(
-                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.",
+                "\r\nConsider the following input:\r\n\r\n```\r\n{witness:#?}\r\n```\r\n\r\nThis panicked, but the payload was not recoverable.\r\n\r\nTo reproduce, set `PBT_SEED={seed}`.",
             );
         }
     }
@@ -3,8 +3,16 @@
 use proc_macro::TokenStream;
 
 /// Derive `::pbt::Pbt` for an arbitrary type.
+///
+/// By default, every type parameter must implement `::pbt::Pbt`.
+/// To replace those bounds, e.g. for phantom parameters,
+/// write `#[pbt(bound = "T: 'static + Clone + core::fmt::Debug")]` on the type.
+///
+/// To confine an integer field to a range, during both generation and shrinking,
+/// write `#[pbt(range = "0..=100")]` on the field.
+/// Shrinking moves toward the in-range value closest to zero.
 #[inline]
-#[proc_macro_derive(Pbt)]
+#[proc_macro_derive(Pbt, attributes(pbt))]
 pub fn derive_pbt(ts: TokenStream) -> TokenStream {
     pbt_macro2::derive_pbt(ts.into()).into()
 }
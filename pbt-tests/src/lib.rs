@@ -5,7 +5,10 @@
 
 //! Tests for `pbt` as seen by downstream crates.
 
-use pbt::{Pbt, pbt};
+use {
+    core::marker::PhantomData,
+    pbt::{Pbt, pbt},
+};
 
 //                                    vvv
 #[derive(Clone, Debug, Eq, PartialEq, Pbt)]
@@ -91,3 +94,433 @@ fn lhs_at_most_rhs(lhs: &usize, rhs: &usize) {
 fn string_len_is_char_count(s: &String) {
     assert_eq!(s.len(), s.chars().count());
 }
+
+/// Lifetime, type, and const parameters, all in one definition.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+pub struct MixedGenerics<'a, T, const N: usize>(PhantomData<&'a ()>, [T; N]);
+
+#[pbt(1)]
+fn mixed_generics_derive(_: &MixedGenerics<'static, bool, 3>) {}
+
+/// Not `Pbt`, so it can only appear where a custom bound drops the automatic one.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct NotPbt;
+
+/// Tagged with a phantom type that needn't implement `Pbt` itself.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[pbt(bound = "T: 'static + Clone + core::fmt::Debug")]
+pub struct Tagged<T>(PhantomData<T>, u8);
+
+#[pbt(1)]
+fn custom_bound_derive(_: &Tagged<NotPbt>) {}
+
+/// A struct with a field confined to a range.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[non_exhaustive]
+pub struct Percent {
+    /// Unconstrained.
+    pub other: u16,
+    /// Always in `10..=100`.
+    #[pbt(range = "10..=100")]
+    pub value: u8,
+}
+
+/// A rose tree, recursive only through `Vec`.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Tree {
+    /// A tree with no children.
+    Leaf,
+    /// A tree with any number of children.
+    Node(Vec<Self>),
+}
+
+impl Tree {
+    /// The length of the longest path from the root to a leaf.
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        match *self {
+            Self::Leaf => 0,
+            Self::Node(ref children) => children
+                .iter()
+                .map(Self::depth)
+                .max()
+                .map_or(1, |deepest| deepest.saturating_add(1)),
+        }
+    }
+
+    /// The greatest number of children of any one node.
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        match *self {
+            Self::Leaf => 0,
+            Self::Node(ref children) => children
+                .iter()
+                .map(Self::width)
+                .fold(children.len(), usize::max),
+        }
+    }
+}
+
+#[pbt]
+#[should_panic(
+    expected = "\r\nConsider the following input:\r\n\r\n```\r\nNode(\n    [\n        Leaf,\n        Leaf,\n        Leaf,\n        Node(\n            [\n                Node(\n                    [\n                        Node(\n                            [],\n                        ),\n                    ],\n                ),\n            ],\n        ),\n    ],\n)\r\n```\r\n\r\nassertion failed: tree.depth() < 4 || tree.width() < 4"
+)]
+fn wide_and_deep_tree(tree: &Tree) {
+    assert!(tree.depth() < 4 || tree.width() < 4);
+}
+
+/// A finite type with fields, so its values can be enumerated.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Light {
+    /// Switched off.
+    Off,
+    /// Switched on.
+    On {
+        /// Whether the light is at full brightness.
+        bright: bool,
+    },
+}
+
+/// Natural numbers in unary, the simplest recursive type.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Pbt)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Peano {
+    /// One more than another natural number.
+    Successor(Box<Self>),
+    /// Zero.
+    Zero,
+}
+
+impl Peano {
+    /// Count successors.
+    #[inline]
+    #[must_use]
+    pub fn to_usize(&self) -> usize {
+        let mut n = 0_usize;
+        let mut current = self;
+        while let Self::Successor(ref predecessor) = *current {
+            n = n.saturating_add(1);
+            current = predecessor;
+        }
+        n
+    }
+}
+
+/// A unit struct, which has exactly one value.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[non_exhaustive]
+pub struct Unit;
+
+/// A generic used in only one of two variants.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Wrap<T> {
+    /// Wraps a value.
+    A(T),
+    /// Wraps nothing.
+    B,
+}
+
+/// Only unit variants, so shrinking can only move to earlier variants.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Pbt)]
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "Variant order is under test: earlier variants are simpler."
+)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Color {
+    /// The first (simplest) variant.
+    Red,
+    /// The second variant.
+    Green,
+    /// The last variant.
+    Blue,
+}
+
+/// Even natural numbers in unary, mutually recursive with [`Odd`].
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Even {
+    /// One more than an odd number.
+    Successor(Box<Odd>),
+    /// Zero.
+    Zero,
+}
+
+/// Odd natural numbers in unary, mutually recursive with [`Even`].
+///
+/// Every value has to pass through `Even` to reach a base case.
+#[derive(Clone, Debug, PartialEq, Pbt)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "This test crate intentionally exposes a complete toy data type."
+)]
+pub enum Odd {
+    /// One more than an even number.
+    Successor(Box<Even>),
+}
+
+impl Even {
+    /// Count successors through both types.
+    #[inline]
+    #[must_use]
+    pub fn to_usize(&self) -> usize {
+        match *self {
+            Self::Successor(ref odd) => odd.to_usize().saturating_add(1),
+            Self::Zero => 0,
+        }
+    }
+}
+
+impl Odd {
+    /// Count successors through both types.
+    #[inline]
+    #[must_use]
+    pub fn to_usize(&self) -> usize {
+        let Self::Successor(ref even) = *self;
+        even.to_usize().saturating_add(1)
+    }
+}
+
+#[test]
+fn enumerate_derived() {
+    let all: Option<Vec<Light>> = pbt::enumerate().map(Iterator::collect);
+    assert_eq!(
+        all,
+        Some(vec![
+            Light::Off,
+            Light::On { bright: false },
+            Light::On { bright: true },
+        ]),
+    );
+}
+
+#[test]
+fn enumeration_consistent_with_derive() {
+    let () = pbt::check_enumeration::<Light>();
+    let () = pbt::check_enumeration::<Color>();
+    let () = pbt::check_enumeration::<(Light, Option<Color>)>();
+}
+
+#[test]
+fn vec_recursive_tree_deterministic() {
+    let trees = || pbt::sample::<Tree>(pbt::DEFAULT_N_CASES, &mut pbt::WyRand::new(42));
+    assert_eq!(trees(), trees());
+}
+
+/// Whether two bytes can be added without overflow. Takes references, as many APIs do.
+#[inline]
+#[must_use]
+pub const fn sum_fits(lhs: &u8, rhs: &u8) -> bool {
+    lhs.checked_add(*rhs).is_some()
+}
+
+#[pbt]
+#[should_panic(expected = "assertion failed: sum_fits(lhs, rhs)")]
+fn u8_sum_fits(lhs: &u8, rhs: &u8) {
+    assert!(sum_fits(lhs, rhs));
+}
+
+#[test]
+fn peano_seed_reproduces_everywhere() {
+    let generated: Vec<usize> = pbt::sample::<Peano>(16, &mut pbt::WyRand::new(42))
+        .iter()
+        .map(Peano::to_usize)
+        .collect();
+    // Hardcoded: if this changes on some platform, reported seeds aren't portable.
+    let expected: Vec<usize> = vec![0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0];
+    assert_eq!(generated, expected);
+}
+
+#[test]
+fn enumerate_unit() {
+    let units: Option<Vec<()>> = pbt::enumerate().map(Iterator::collect);
+    assert_eq!(units, Some(vec![()]));
+    let derived: Option<Vec<Unit>> = pbt::enumerate().map(Iterator::collect);
+    assert_eq!(derived, Some(vec![Unit]));
+}
+
+#[test]
+fn peano_shrink_terminates() {
+    let mut peano = Peano::Zero;
+    for _ in 0_u8..20 {
+        peano = Peano::Successor(Box::new(peano));
+    }
+    let () = pbt::check_shrink_termination(&peano, 100);
+}
+
+#[test]
+fn ranged_field_stays_in_range() {
+    let mut prng = pbt::WyRand::new(42);
+    assert!(
+        pbt::sample::<Percent>(pbt::DEFAULT_N_CASES, &mut prng)
+            .iter()
+            .all(|percent| (10..=100).contains(&percent.value)),
+    );
+    let witness = pbt::witness(
+        |percent: &Percent| {
+            // Also called on every shrinking candidate:
+            assert!((10..=100).contains(&percent.value), "{percent:?}");
+            (percent.value >= 50).then_some(())
+        },
+        pbt::DEFAULT_N_CASES,
+        &mut prng,
+    );
+    assert_eq!(
+        witness,
+        Some((
+            Percent {
+                other: 0,
+                value: 50,
+            },
+            (),
+        )),
+    );
+}
+
+#[test]
+fn peano_samples_vary() {
+    let samples = pbt::sample::<Peano>(10, &mut pbt::WyRand::new(42));
+    assert_eq!(samples.len(), 10);
+    assert!(
+        samples
+            .windows(2)
+            .any(|pair| matches!(*pair, [ref a, ref b] if a != b)),
+        "{samples:?}"
+    );
+}
+
+#[test]
+fn unit_variants_shrink_to_earlier_variants() {
+    let from_blue = |property: fn(&Color) -> Option<()>| {
+        pbt::witness_with_generator(
+            &|_: &mut pbt::WyRand| Color::Blue,
+            property,
+            1,
+            &mut pbt::WyRand::new(42),
+        )
+    };
+    assert_eq!(
+        from_blue(|&color| (color != Color::Green).then_some(())),
+        Some((Color::Red, ())),
+    );
+    assert_eq!(
+        from_blue(|&color| (color != Color::Red).then_some(())),
+        Some((Color::Green, ())),
+    );
+}
+
+#[test]
+fn peano_near_miss() {
+    use pbt::persist;
+
+    let n_cases = 100;
+    let (witness, closest) = pbt::witness_scored(
+        |peano: &Peano| (peano.to_usize() > 1_000).then_some(()),
+        |peano| i64::try_from(peano.to_usize()).unwrap_or(i64::MAX),
+        n_cases,
+        &mut pbt::WyRand::new(42),
+    );
+    assert_eq!(witness, None);
+    // Persisted witnesses (e.g. from `peano_verbose_witness`) are scored too:
+    let largest = persist::replay::<Peano>()
+        .iter()
+        .chain(&pbt::sample::<Peano>(n_cases, &mut pbt::WyRand::new(42)))
+        .map(Peano::to_usize)
+        .max();
+    assert_eq!(closest.as_ref().map(Peano::to_usize), largest);
+}
+
+#[test]
+fn generic_in_some_variants() {
+    assert_eq!(pbt::cardinality::<Wrap<bool>>(), Some(3));
+    let () = pbt::check_enumeration::<Wrap<bool>>();
+    let samples = pbt::sample::<Wrap<Peano>>(pbt::DEFAULT_N_CASES, &mut pbt::WyRand::new(42));
+    assert!(samples.contains(&Wrap::B), "`B` never generated");
+    let witness = pbt::witness(
+        |wrap: &Wrap<Peano>| match *wrap {
+            Wrap::A(ref peano) => (peano.to_usize() >= 2).then_some(()),
+            Wrap::B => None,
+        },
+        pbt::DEFAULT_N_CASES,
+        &mut pbt::WyRand::new(42),
+    );
+    let two = Peano::Successor(Box::new(Peano::Successor(Box::new(Peano::Zero))));
+    assert_eq!(witness, Some((Wrap::A(two), ())));
+}
+
+#[test]
+fn peano_verbose_witness() {
+    let found = pbt::witness_verbose(
+        |peano: &Peano| peano.to_usize().checked_sub(42),
+        pbt::DEFAULT_N_CASES,
+        &mut pbt::WyRand::new(42),
+    );
+    assert!(
+        matches!(
+            found,
+            Some((ref original, ref minimal, 0))
+                if original.to_usize() >= 42 && minimal.to_usize() == 42,
+        ),
+        "{found:?}",
+    );
+}
+
+#[test]
+fn mutually_recursive_witness() {
+    let samples = pbt::sample::<Odd>(pbt::DEFAULT_N_CASES, &mut pbt::WyRand::new(42));
+    assert!(samples.iter().all(|odd| odd.to_usize() & 1 == 1));
+    let witness = pbt::witness(
+        |even: &Even| even.to_usize().checked_sub(4),
+        pbt::DEFAULT_N_CASES,
+        &mut pbt::WyRand::new(42),
+    );
+    assert!(
+        matches!(witness, Some((ref even, 0)) if even.to_usize() == 4),
+        "{witness:?}",
+    );
+}
+
+#[test]
+fn peano_hash_agrees_with_eq() {
+    use {core::hash::BuildHasher as _, pbt::pairs::EqPair, std::hash::RandomState};
+
+    let state = RandomState::new();
+    let pairs = pbt::sample::<EqPair<Peano>>(pbt::DEFAULT_N_CASES, &mut pbt::WyRand::new(42));
+    assert!(pairs.iter().any(|pair| pair.0 == pair.1), "no equal pairs");
+    assert!(
+        pairs.iter().any(|pair| pair.0 != pair.1),
+        "no unequal pairs"
+    );
+    let witness = pbt::witness(
+        |pair: &EqPair<Peano>| {
+            (pair.0 == pair.1 && state.hash_one(&pair.0) != state.hash_one(&pair.1)).then_some(())
+        },
+        pbt::DEFAULT_N_CASES,
+        &mut pbt::WyRand::new(42),
+    );
+    assert_eq!(witness, None);
+}
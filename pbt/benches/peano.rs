@@ -0,0 +1,32 @@
+//! Batch generation throughput for a simple inductive type.
+
+use {
+    core::hint::black_box,
+    criterion::{Criterion, Throughput, criterion_group, criterion_main},
+    pbt::Pbt,
+};
+
+/// Natural numbers in unary.
+#[derive(Clone, Debug, Pbt)]
+enum Peano {
+    /// One more than another natural number.
+    Successor(Box<Self>),
+    /// Zero.
+    Zero,
+}
+
+/// Measure one batch of 100,000 values, including periodic swarm construction.
+fn generate_100_000(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("peano");
+    let _: &mut _ = group.throughput(Throughput::Elements(100_000));
+    let _: &mut _ = group.bench_function("sample_100_000", |bencher| {
+        bencher.iter(|| {
+            let mut prng = pbt::WyRand::new(42);
+            black_box(pbt::sample::<Peano>(100_000, &mut prng));
+        });
+    });
+    let () = group.finish();
+}
+
+criterion_group!(benches, generate_100_000);
+criterion_main!(benches);
@@ -12,7 +12,37 @@ use {
     wyrand::WyRand,
 };
 
-/// Generate an arbitrary term of any type `T`.
+/// Generate an arbitrary term of any type `T`,
+/// starting with any persisted witnesses of type `T`.
+///
+/// # Errors
+///
+/// If `T` is uninstantiable.
+#[inline]
+pub(crate) fn arbitrary<T>(prng: &mut WyRand) -> Result<impl Iterator<Item = T>, Uninstantiable>
+where
+    T: Pbt,
+{
+    let fresh = generate(prng)?;
+    Ok(persist::replay().into_iter().chain(fresh))
+}
+
+/// Generate a single fresh arbitrary term of any type `T` at a specific size.
+///
+/// # Errors
+///
+/// If `T` is uninstantiable.
+#[inline]
+pub(crate) fn exactly<T>(size: Size, prng: &mut WyRand) -> Result<T, Uninstantiable>
+where
+    T: Pbt,
+{
+    let () = register_globally::<T>();
+    let swarm = Swarm::new::<T>(prng, &mut map())?;
+    Ok(swarm.arbitrary(size, prng))
+}
+
+/// Generate fresh arbitrary terms of any type `T`, without replaying persisted witnesses.
 ///
 /// # Errors
 ///
@@ -22,7 +52,7 @@ use {
     clippy::expect_used,
     reason = "Internal invariants: violations should fail loudly."
 )]
-pub(crate) fn arbitrary<T>(prng: &mut WyRand) -> Result<impl Iterator<Item = T>, Uninstantiable>
+pub(crate) fn generate<T>(prng: &mut WyRand) -> Result<impl Iterator<Item = T>, Uninstantiable>
 where
     T: Pbt,
 {
@@ -31,21 +61,19 @@ where
     let mut swarm = Swarm::new::<T>(prng, &mut swarm_cache)?;
     let mut batch_size = 1_usize; // Increases over time.
     let mut remaining_in_batch = batch_size;
-    Ok(persist::replay()
-        .into_iter()
-        .chain(Size::increasing().map(move |size| {
-            if let Some(decremented) = remaining_in_batch.checked_sub(1) {
-                remaining_in_batch = decremented;
-            } else {
-                remaining_in_batch = batch_size;
-                #[expect(
-                    clippy::arithmetic_side_effects,
-                    reason = "The hardware will die before batch size overflows."
-                )]
-                let () = batch_size += 1;
-                swarm = Swarm::new::<T>(prng, &mut swarm_cache)
-                    .expect("INTERNAL ERROR (`pbt`): instantiability changed mid-generation");
-            }
-            swarm.arbitrary(size, prng)
-        })))
+    Ok(Size::increasing().map(move |size| {
+        if let Some(decremented) = remaining_in_batch.checked_sub(1) {
+            remaining_in_batch = decremented;
+        } else {
+            remaining_in_batch = batch_size;
+            #[expect(
+                clippy::arithmetic_side_effects,
+                reason = "The hardware will die before batch size overflows."
+            )]
+            let () = batch_size += 1;
+            swarm = Swarm::new::<T>(prng, &mut swarm_cache)
+                .expect("INTERNAL ERROR (`pbt`): instantiability changed mid-generation");
+        }
+        swarm.arbitrary(size, prng)
+    }))
 }
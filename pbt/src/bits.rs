@@ -0,0 +1,401 @@
+//! Compact bit-level data, e.g. flags, without allocating a `Vec<bool>`,
+//! and fixed-width byte strings, e.g. addresses, hashes, or UUIDs.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        impls::shrink,
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::{array, iter, num::NonZero},
+    wyrand::WyRand,
+};
+
+/// Bitsets likely to trip up flag-handling code.
+const EDGE_CASES: [u8; 10] = [
+    0b_0000_0000,
+    0b_1111_1111,
+    0b_0000_0001,
+    0b_0000_0010,
+    0b_0000_0100,
+    0b_0000_1000,
+    0b_0001_0000,
+    0b_0010_0000,
+    0b_0100_0000,
+    0b_1000_0000,
+];
+
+/// Eight independent flags packed into a single byte.
+///
+/// Generation either flips each bit independently
+/// or chooses an edge case: all clear, all set, or exactly one bit set.
+/// Shrinking clears bits and moves set bits toward the least significant position.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "every `u8` is a valid set of eight flags"
+)]
+pub struct BitSet8(pub u8);
+
+/// Exactly `N` bytes, e.g. an IPv4 address (`Bytes<4>`) or a UUID (`Bytes<16>`).
+///
+/// Generation either fills every byte uniformly
+/// or chooses an edge case: all zeros, all ones (`0xFF`), or incrementing (`0, 1, 2, ...`).
+/// Shrinking tries all zeros, then moves each byte toward `0`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "every `[u8; N]` is a valid `Bytes<N>`"
+)]
+pub struct Bytes<const N: usize>(pub [u8; N]);
+
+impl BitSet8 {
+    /// Whether the `i`th bit (counting from the least significant) is set.
+    ///
+    /// # Panics
+    ///
+    /// If `i >= 8`.
+    #[inline]
+    #[must_use]
+    pub const fn get(self, i: u32) -> bool {
+        assert!(i < u8::BITS, "bit index out of range");
+        (self.0 >> i) & 1 != 0
+    }
+
+    /// Set or clear the `i`th bit (counting from the least significant).
+    ///
+    /// # Panics
+    ///
+    /// If `i >= 8`.
+    #[inline]
+    pub const fn set(&mut self, i: u32, value: bool) {
+        assert!(i < u8::BITS, "bit index out of range");
+        if value {
+            self.0 |= 1 << i;
+        } else {
+            self.0 &= !(1 << i);
+        }
+    }
+}
+
+impl Pbt for BitSet8 {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`BitSet8` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                s.parse().ok().map(Self)
+            },
+            generators: vec![
+                #[expect(
+                    clippy::as_conversions,
+                    clippy::cast_possible_truncation,
+                    reason = "intentional: keep only the low bits"
+                )]
+                |prng| Self(prng.rand() as u8),
+                |prng| Self(modulo::pick(prng, &EDGE_CASES)),
+            ],
+            serialize: |&Self(bits)| bits.to_string().into(),
+            shrink,
+        }
+    }
+}
+
+impl<const N: usize> Default for Bytes<N> {
+    #[inline]
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> Pbt for Bytes<N> {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Bytes` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::Array(ref json_bytes) = *json else {
+                    return None;
+                };
+                let bytes: Vec<u8> = json_bytes
+                    .iter()
+                    .map(|byte| u8::try_from(byte.as_u64()?).ok())
+                    .collect::<Option<_>>()?;
+                bytes.try_into().ok().map(Self)
+            },
+            generators: vec![uniform_bytes, edge_case_bytes],
+            serialize: |&Self(bytes)| bytes.iter().copied().collect(),
+            shrink: shrink_bytes,
+        }
+    }
+}
+
+/// Clear all bits, then clear each set bit,
+/// then move each set bit one position lower if that position is clear.
+///
+/// Every candidate is numerically smaller than the original.
+#[inline]
+fn shrink(BitSet8(bits): BitSet8) -> Box<dyn Iterator<Item = BitSet8>> {
+    let set_bits = (0..u8::BITS).rev().filter(move |&i| (bits >> i) & 1 != 0);
+    let cleared = set_bits.clone().map(move |i| bits & !(1 << i));
+    let lowered = set_bits.filter_map(move |i| {
+        let lower = i.checked_sub(1)?;
+        ((bits >> lower) & 1 == 0).then_some((bits & !(1 << i)) | (1 << lower))
+    });
+    Box::new(
+        iter::once(0)
+            .filter(move |_| bits != 0)
+            .chain(cleared)
+            .chain(lowered)
+            .map(BitSet8),
+    )
+}
+
+/// Choose all zeros, all ones (`0xFF`), or incrementing bytes (`0, 1, 2, ...`).
+#[inline]
+fn edge_case_bytes<const N: usize>(prng: &mut WyRand) -> Bytes<N> {
+    match modulo::below(prng, const { NonZero::new(3).unwrap() }) {
+        0 => Bytes([0; N]),
+        1 => Bytes([0xFF; N]),
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "intentional: wrap around after `0xFF`"
+        )]
+        _ => Bytes(array::from_fn(|i| i as u8)),
+    }
+}
+
+/// Zero all bytes, then shrink each byte toward `0`
+/// by repeatedly subtracting half the previous shrunk amount.
+#[inline]
+pub(crate) fn shrink_bytes<const N: usize>(
+    Bytes(bytes): Bytes<N>,
+) -> Box<dyn Iterator<Item = Bytes<N>>> {
+    let lowered = (0..N).flat_map(move |i| {
+        let byte = bytes.get(i).copied().unwrap_or(0);
+        (shrink!(u8))(byte).filter_map(move |shrunk| {
+            let mut lowered = bytes;
+            *lowered.get_mut(i)? = shrunk;
+            Some(lowered)
+        })
+    });
+    Box::new(
+        iter::once([0; N])
+            .filter(move |zeros| bytes != *zeros)
+            .chain(lowered)
+            .map(Bytes),
+    )
+}
+
+/// Fill every byte uniformly.
+#[inline]
+pub(crate) fn uniform_bytes<const N: usize>(prng: &mut WyRand) -> Bytes<N> {
+    let mut bytes = [0; N];
+    for chunk in bytes.chunks_mut(8) {
+        #[expect(
+            clippy::little_endian_bytes,
+            reason = "a fixed byte order keeps generation reproducible across platforms"
+        )]
+        let random = prng.rand().to_le_bytes();
+        let () = chunk.copy_from_slice(random.get(..chunk.len()).unwrap_or_default());
+    }
+    Bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<BitSet8>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<BitSet8> = persist::replay();
+        let () = expected.extend([4, 0, 64, 230, 88, 168, 1, 8, 16, 2].map(BitSet8));
+        let generated: Vec<BitSet8> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn deterministic_shrink() {
+        let shrunk: Vec<u8> = shrink(BitSet8(0b_1010_0100))
+            .map(|BitSet8(bits)| bits)
+            .collect();
+        let expected: Vec<u8> = vec![
+            0b_0000_0000,
+            0b_0010_0100,
+            0b_1000_0100,
+            0b_1010_0000,
+            0b_0110_0100,
+            0b_1001_0100,
+            0b_1010_0010,
+        ];
+        assert_eq!(shrunk, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<BitSet8>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<BitSet8>();
+    }
+
+    #[test]
+    fn get_and_set() {
+        let mut bits = BitSet8::default();
+        let () = bits.set(3, true);
+        let () = bits.set(7, true);
+        let () = bits.set(3, false);
+        assert_eq!(bits, BitSet8(0b_1000_0000));
+        assert!(bits.get(7));
+        assert!(!bits.get(3));
+    }
+
+    #[test]
+    fn minimal_more_than_3_bits_set() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&BitSet8(bits): &BitSet8| (bits.count_ones() > 3).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((BitSet8(0b_0000_1111), ())),
+        );
+    }
+
+    #[test]
+    fn bytes_deterministic() {
+        let () = register_globally::<Bytes<4>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Bytes<4>> = persist::replay();
+        let () = expected.extend(
+            [
+                [0, 0, 0, 0],
+                [0, 1, 2, 3],
+                [0, 1, 2, 3],
+                [230, 200, 177, 203],
+                [88, 69, 255, 54],
+                [168, 67, 165, 3],
+                [0, 1, 2, 3],
+                [255, 255, 255, 255],
+                [0, 1, 2, 3],
+                [0, 0, 0, 0],
+            ]
+            .map(Bytes),
+        );
+        let generated: Vec<Bytes<4>> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn bytes_deterministic_shrink() {
+        let shrunk: Vec<[u8; 2]> = shrink_bytes(Bytes([3, 8]))
+            .map(|Bytes(bytes)| bytes)
+            .collect();
+        let expected: Vec<[u8; 2]> = vec![[0, 0], [0, 8], [2, 8], [3, 0], [3, 4], [3, 6], [3, 7]];
+        assert_eq!(shrunk, expected);
+    }
+
+    #[test]
+    fn bytes_eta_expansion() {
+        let () = check_eta_expansion::<Bytes<16>>();
+    }
+
+    #[test]
+    fn bytes_serialization() {
+        let () = check_serialization::<Bytes<16>>();
+        let () = check_serialization::<Bytes<0>>();
+    }
+
+    #[test]
+    fn bytes_generates_every_edge_case() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Bytes<16>> = arbitrary(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .collect();
+        assert!(generated.contains(&Bytes([0; 16])));
+        assert!(generated.contains(&Bytes([0xFF; 16])));
+        assert!(generated.contains(&Bytes(array::from_fn(|i| u8::try_from(i).unwrap()))));
+    }
+
+    #[test]
+    fn minimal_uuid_sized_unsorted() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&Bytes(bytes): &Bytes<16>| (!bytes.is_sorted()).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Bytes([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0]), (),)),
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! Integers confined to a domain, e.g. a percentage, a port range, or a residue modulo `M`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        impls::shrink,
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::iter,
+    wyrand::WyRand,
+};
+
+/// An integer in the inclusive range `LO..=HI`.
+///
+/// Generation either chooses uniformly from the whole range
+/// or chooses an edge case: `LO`, `HI`, or `0` (if in range).
+/// Shrinking moves toward the in-range value closest to zero.
+/// Literal types count as infinite, so `cardinality` and `enumerate` return `None`.
+///
+/// An empty range is a compile-time error:
+/// ```compile_fail
+/// let _ = pbt::bounded::Bounded::<5, -5>::new(0);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Bounded<const LO: i64, const HI: i64>(i64);
+
+/// A residue modulo `M`, i.e. an integer in `0..M`.
+///
+/// Generation either chooses uniformly from `0..M`
+/// or chooses an edge case: `0`, `1`, or `M - 1`.
+/// Shrinking moves toward `0`.
+/// Literal types count as infinite, so `cardinality` doesn't report `M`.
+///
+/// `M == 0` is a compile-time error:
+/// ```compile_fail
+/// let _ = pbt::bounded::Mod::<0>::new(0);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Mod<const M: u64>(u64);
+
+impl<const LO: i64, const HI: i64> Bounded<LO, HI> {
+    /// Rejects empty ranges as soon as this type is used.
+    const NONEMPTY: () = assert!(LO <= HI, "`Bounded<LO, HI>` requires `LO <= HI`");
+
+    /// The in-range value closest to zero, toward which values shrink.
+    const SIMPLEST: i64 = if LO > 0 {
+        LO
+    } else if HI < 0 {
+        HI
+    } else {
+        0
+    };
+
+    /// The in-range value nearest to an integer.
+    #[inline]
+    #[must_use]
+    pub const fn clamped(i: i64) -> Self {
+        let () = Self::NONEMPTY;
+        Self(if i < LO {
+            LO
+        } else if i > HI {
+            HI
+        } else {
+            i
+        })
+    }
+
+    /// The underlying integer.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> i64 {
+        self.0
+    }
+
+    /// Wrap an integer if and only if it's in `LO..=HI`.
+    #[inline]
+    #[must_use]
+    pub const fn new(i: i64) -> Option<Self> {
+        let () = Self::NONEMPTY;
+        if LO <= i && i <= HI {
+            Some(Self(i))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const M: u64> Mod<M> {
+    /// Rejects `M == 0` as soon as this type is used.
+    const NONZERO: () = assert!(M > 0, "`Mod<M>` requires `M > 0`");
+
+    /// The underlying integer.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Wrap an integer if and only if it's in `0..M`.
+    #[inline]
+    #[must_use]
+    pub const fn new(n: u64) -> Option<Self> {
+        let () = Self::NONZERO;
+        if n < M { Some(Self(n)) } else { None }
+    }
+
+    /// The residue of an integer modulo `M`.
+    #[inline]
+    #[must_use]
+    pub const fn reduced(n: u64) -> Self {
+        let () = Self::NONZERO;
+        Self(match n.checked_rem(M) {
+            Some(residue) => residue,
+            None => 0,
+        })
+    }
+}
+
+impl<const LO: i64, const HI: i64> Pbt for Bounded<LO, HI> {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Bounded` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = Self::NONEMPTY;
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                Self::new(s.parse().ok()?)
+            },
+            generators: vec![uniform, |prng| {
+                let edge_cases = [LO, HI, Self::SIMPLEST];
+                Self(modulo::pick(prng, &edge_cases))
+            }],
+            serialize: |&Self(i)| i.to_string().into(),
+            shrink,
+        }
+    }
+}
+
+impl<const M: u64> Pbt for Mod<M> {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Mod` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = Self::NONZERO;
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                Self::new(s.parse().ok()?)
+            },
+            generators: vec![|prng| Self::reduced(prng.rand()), |prng| {
+                let edge_cases = [0, 1, M.saturating_sub(1)];
+                Self::reduced(modulo::pick(prng, &edge_cases))
+            }],
+            serialize: |&Self(n)| n.to_string().into(),
+            shrink: |Self(n)| Box::new((shrink!(u64))(n).map(Self)),
+        }
+    }
+}
+
+/// Move toward the in-range value closest to zero
+/// by repeatedly subtracting half the previous shrunk distance.
+#[inline]
+fn shrink<const LO: i64, const HI: i64>(
+    Bounded(i): Bounded<LO, HI>,
+) -> Box<dyn Iterator<Item = Bounded<LO, HI>>> {
+    let simplest = Bounded::<LO, HI>::SIMPLEST;
+    let above = i > simplest;
+    let distance = i.abs_diff(simplest);
+    let mut shift = 0;
+    Box::new(iter::from_fn(move || {
+        let delta = distance.checked_shr(shift)?;
+        if delta == 0 {
+            return None;
+        }
+        shift = shift.checked_add(1)?;
+        let shrunk = distance.checked_sub(delta)?;
+        if above {
+            simplest.checked_add_unsigned(shrunk)
+        } else {
+            simplest.checked_sub_unsigned(shrunk)
+        }
+        .map(Bounded)
+    }))
+}
+
+/// Choose uniformly from the whole range `LO..=HI`.
+#[inline]
+fn uniform<const LO: i64, const HI: i64>(prng: &mut WyRand) -> Bounded<LO, HI> {
+    let span = HI.abs_diff(LO);
+    let random = prng.rand();
+    // If `span + 1` overflows, the range covers every `i64`:
+    let offset = span
+        .checked_add(1)
+        .and_then(|n| random.checked_rem(n))
+        .unwrap_or(random);
+    Bounded(LO.wrapping_add_unsigned(offset))
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<i64> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(Bounded::<-5, 5>::get)
+            .collect();
+        let expected: Vec<i64> = vec![-5, 0, 0, -2, -4, -4, 0, 5, 0, -5];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn deterministic_shrink() {
+        let shrunk: Vec<i64> = shrink(Bounded::<-100, -10>(-50))
+            .map(Bounded::get)
+            .collect();
+        let expected: Vec<i64> = vec![-10, -30, -40, -45, -48, -49];
+        assert_eq!(shrunk, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Bounded<-5, 5>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Bounded<-5, 5>>();
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(Bounded::<-5, 5>::clamped(-100).get(), -5);
+        assert_eq!(Bounded::<-5, 5>::clamped(3).get(), 3);
+        assert_eq!(Bounded::<-5, 5>::clamped(100).get(), 5);
+    }
+
+    #[test]
+    fn full_range() {
+        let () = check_serialization::<Bounded<{ i64::MIN }, { i64::MAX }>>();
+    }
+
+    #[test]
+    fn never_out_of_range() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |b: &Bounded<-5, 5>| (!(-5..=5).contains(&b.get())).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn single_value() {
+        let mut prng = WyRand::new(42);
+        assert!(
+            arbitrary::<Bounded<7, 7>>(&mut prng)
+                .unwrap()
+                .take(DEFAULT_N_CASES)
+                .all(|b| b.get() == 7)
+        );
+    }
+
+    #[test]
+    fn shrinks_toward_lower_bound() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |b: &Bounded<10, 20>| (b.get() >= 15).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Bounded(15), ())),
+        );
+    }
+
+    #[test]
+    fn mod_deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<u64> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(Mod::<10>::get)
+            .collect();
+        let expected: Vec<u64> = vec![0, 9, 9, 2, 2, 0, 9, 1, 9, 0];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn mod_eta_expansion() {
+        let () = check_eta_expansion::<Mod<7>>();
+    }
+
+    #[test]
+    fn mod_serialization() {
+        let () = check_serialization::<Mod<7>>();
+    }
+
+    #[test]
+    fn mod_reduced() {
+        assert_eq!(Mod::<7>::reduced(15).get(), 1);
+        assert_eq!(Mod::<7>::new(7), None);
+        assert_eq!(Mod::<{ u64::MAX }>::reduced(u64::MAX).get(), 0);
+    }
+
+    #[test]
+    fn mod_never_out_of_range() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |m: &Mod<7>| (m.get() >= 7).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn mod_single_value() {
+        let mut prng = WyRand::new(42);
+        assert!(
+            arbitrary::<Mod<1>>(&mut prng)
+                .unwrap()
+                .take(DEFAULT_N_CASES)
+                .all(|m| m.get() == 0)
+        );
+    }
+
+    #[test]
+    fn mod_shrinks_toward_zero() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |m: &Mod<7>| (m.get() >= 4).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Mod(4), ())),
+        );
+    }
+}
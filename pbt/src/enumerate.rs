@@ -0,0 +1,312 @@
+//! Exhaustive enumeration of finite types, e.g. `bool` or field-less `enum`s.
+//!
+//! A type is finite iff it's algebraic, not recursive,
+//! and every field of every instantiable variant is finite.
+//! Literal types (e.g. `u8`) are treated as infinite
+//! since their generators are opaque.
+
+use {
+    crate::{
+        Pbt,
+        fields::Fields,
+        hash::map,
+        reflection::{Constructor, Constructors, Parts, constructors_of, register_globally},
+    },
+    ahash::HashMap,
+    alloc::{sync::Arc, vec},
+    core::{
+        any::{Any, TypeId},
+        marker::PhantomData,
+        num::NonZero,
+    },
+};
+
+/// Construct each combination of fields in turn,
+/// treating each field as one digit whose radix is
+/// the number of values of that field's type.
+struct Odometer {
+    /// For each field type requested so far, the values of that type constructed so far
+    /// (so that no field type is enumerated any further than the odometer has reached)
+    /// and an iterator over the rest.
+    cache: HashMap<TypeId, Box<dyn Any>>,
+    /// The index of the value to use for each field, in the order `construct` requests them.
+    digits: Vec<usize>,
+    /// How many fields `construct` has requested during this construction.
+    position: usize,
+    /// The number of values of each field's type, in the order `construct` requests them.
+    radices: Vec<usize>,
+}
+
+/// Every value of a finite type, constructed lazily; see `values`.
+pub(crate) struct Values<T> {
+    /// The variant being enumerated and the combination of fields to construct next.
+    current: Option<(NonZero<usize>, Odometer)>,
+    /// This iterator yields `T`s but doesn't own any.
+    phantom: PhantomData<fn() -> T>,
+    /// The remaining instantiable variants, in source order.
+    variants: vec::IntoIter<NonZero<usize>>,
+}
+
+impl Odometer {
+    /// Advance to the next combination of fields, or return `false` if all have been used.
+    #[inline]
+    fn advance(&mut self) -> bool {
+        self.position = 0;
+        for (digit, &radix) in self.digits.iter_mut().zip(&self.radices).rev() {
+            match digit.checked_add(1) {
+                Some(incremented) if incremented < radix => {
+                    *digit = incremented;
+                    return true;
+                }
+                _ => *digit = 0,
+            }
+        }
+        false
+    }
+
+    /// Start at the first combination of fields.
+    #[inline]
+    fn new() -> Self {
+        Self {
+            cache: map(),
+            digits: vec![],
+            position: 0,
+            radices: vec![],
+        }
+    }
+}
+
+impl Fields for &mut Odometer {
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        reason = "Internal invariants: violations should fail loudly."
+    )]
+    fn field<T>(&mut self) -> T
+    where
+        T: Pbt,
+    {
+        let position = self.position;
+        self.position = position
+            .checked_add(1)
+            .expect("INTERNAL ERROR (`pbt`): too many fields");
+        if position == self.digits.len() {
+            let () = self.digits.push(0);
+            let () = self.radices.push(
+                count::<T>(&mut vec![])
+                    .expect("INTERNAL ERROR (`pbt`): infinite field of finite type"),
+            );
+        }
+        let &digit = self
+            .digits
+            .get(position)
+            .expect("INTERNAL ERROR (`pbt`): fields requested out of order");
+        let &mut (ref mut seen, ref mut unseen): &mut (Vec<T>, Values<T>) = self
+            .cache
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| {
+                Box::new((
+                    Vec::<T>::new(),
+                    values::<T>().expect("INTERNAL ERROR (`pbt`): infinite field of finite type"),
+                ))
+            })
+            .downcast_mut()
+            .expect("INTERNAL ERROR (`pbt`): mistyped enumeration cache");
+        while seen.len() <= digit {
+            let () = seen.push(
+                unseen
+                    .next()
+                    .expect("INTERNAL ERROR (`pbt`): field digit out of range"),
+            );
+        }
+        seen.get(digit)
+            .expect("INTERNAL ERROR (`pbt`): field digit out of range")
+            .clone()
+    }
+}
+
+impl<T> Iterator for Values<T>
+where
+    T: Pbt,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some((variant_index, ref mut odometer)) = self.current {
+                let value = T::construct(Parts {
+                    fields: &mut *odometer,
+                    variant_index: Some(variant_index),
+                });
+                if !odometer.advance() {
+                    self.current = None;
+                }
+                return Some(value);
+            }
+            self.current = Some((self.variants.next()?, Odometer::new()));
+        }
+    }
+}
+
+/// Every value of a finite type, or `None` if the type is infinite
+/// or has more values than fit in a `usize`.
+///
+/// This allocates every value at once,
+/// so call it only after checking `count` against some limit;
+/// otherwise, iterate over `values` instead.
+#[inline]
+pub(crate) fn all<T>() -> Option<Vec<T>>
+where
+    T: Pbt,
+{
+    Some(values::<T>()?.collect())
+}
+
+/// The number of values of a type, or `None` if the type is infinite
+/// or has more values than fit in a `usize`.
+///
+/// `visiting` holds every type whose count depends on this one,
+/// so finding this type there means it's recursive (and therefore infinite).
+#[inline]
+pub(crate) fn count<T>(visiting: &mut Vec<TypeId>) -> Option<usize>
+where
+    T: Pbt,
+{
+    let () = register_globally::<T>();
+    count_erased(TypeId::of::<T>(), visiting)
+}
+
+/// The number of values of a registered type; see `count`.
+#[inline]
+fn count_erased(ty: TypeId, visiting: &mut Vec<TypeId>) -> Option<usize> {
+    if visiting.contains(&ty) {
+        return None;
+    }
+    let Constructors::Algebraic(ctors) = constructors_of(ty) else {
+        return None;
+    };
+    let () = visiting.push(ty);
+    let total = ctors.iter().try_fold(0_usize, |acc, ctor| {
+        acc.checked_add(count_fields(ctor, visiting)?)
+    });
+    let _: Option<TypeId> = visiting.pop();
+    total
+}
+
+/// The number of combinations of values for all fields of a constructor.
+#[inline]
+fn count_fields(ctor: &Constructor, visiting: &mut Vec<TypeId>) -> Option<usize> {
+    ctor.field_types()
+        .iter()
+        .try_fold(1_usize, |acc, (&ty, multiplicity)| {
+            let n = count_erased(ty, visiting)?;
+            acc.checked_mul(n.checked_pow(u32::try_from(multiplicity.get()).ok()?)?)
+        })
+}
+
+/// Every value of a finite type, one at a time, or `None` if the type is infinite
+/// or has more values than fit in a `usize`.
+///
+/// Variants appear in source order,
+/// and fields vary from last to first.
+#[inline]
+pub(crate) fn values<T>() -> Option<Values<T>>
+where
+    T: Pbt,
+{
+    let _: usize = count::<T>(&mut vec![])?;
+    let Constructors::Algebraic(ctors) = constructors_of(TypeId::of::<T>()) else {
+        return None;
+    };
+    let mut variants = vec![];
+    for ctor in in_source_order(&ctors) {
+        if count_fields(ctor, &mut vec![])? != 0 {
+            let () = variants.push(ctor.index);
+        }
+    }
+    Some(Values {
+        current: None,
+        phantom: PhantomData,
+        variants: variants.into_iter(),
+    })
+}
+
+/// Constructors sorted by their position in source code.
+#[inline]
+fn in_source_order(ctors: &Arc<[Constructor]>) -> Vec<&Constructor> {
+    let mut sorted: Vec<&Constructor> = ctors.iter().collect();
+    let () = sorted.sort_by_key(|ctor| ctor.index);
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        core::{array, convert::Infallible},
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn count_bool() {
+        assert_eq!(count::<bool>(&mut vec![]), Some(2));
+    }
+
+    #[test]
+    fn count_products_and_sums() {
+        assert_eq!(count::<(bool, bool, bool)>(&mut vec![]), Some(8));
+        assert_eq!(count::<Option<(bool, Option<bool>)>>(&mut vec![]), Some(7));
+    }
+
+    #[test]
+    fn count_uninstantiable() {
+        assert_eq!(count::<Infallible>(&mut vec![]), Some(0));
+        assert_eq!(count::<Option<Infallible>>(&mut vec![]), Some(1));
+    }
+
+    #[test]
+    fn count_infinite() {
+        assert_eq!(count::<u8>(&mut vec![]), None);
+        assert_eq!(count::<Vec<bool>>(&mut vec![]), None);
+        assert_eq!(count::<Option<u8>>(&mut vec![]), None);
+    }
+
+    #[test]
+    fn count_phantom() {
+        assert_eq!(count::<PhantomData<u8>>(&mut vec![]), Some(1));
+    }
+
+    #[test]
+    fn all_bool_pairs() {
+        assert_eq!(
+            all::<(bool, bool)>(),
+            Some(vec![
+                (false, false),
+                (false, true),
+                (true, false),
+                (true, true)
+            ]),
+        );
+    }
+
+    #[test]
+    fn all_options() {
+        assert_eq!(
+            all::<Option<bool>>(),
+            Some(vec![None, Some(false), Some(true)]),
+        );
+    }
+
+    #[test]
+    fn huge_fields_are_enumerated_lazily() {
+        assert_eq!(
+            values::<Option<[bool; 40]>>().map(|values| values.take(3).collect::<Vec<_>>()),
+            Some(vec![
+                None,
+                Some([false; 40]),
+                Some(array::from_fn(|i| i == 39))
+            ]),
+        );
+    }
+}
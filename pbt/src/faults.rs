@@ -0,0 +1,166 @@
+//! Deterministic fault injection, e.g. for testing retry and backoff logic.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+/// An operation that succeeds a fixed number of times and then fails forever,
+/// e.g. a flaky connection, a full disk, or an exhausted quota.
+///
+/// `FailingAt(n)` succeeds on calls `0..n` and fails on every call from `n` onward,
+/// so `FailingAt(0)` fails immediately.
+/// Generation draws `n` from the same distributions as `usize`,
+/// so early failures are common but very late failures are reachable.
+/// Shrinking moves toward `FailingAt(0)`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "a transparent newtype meant to be constructed directly"
+)]
+pub struct FailingAt(pub usize);
+
+impl FailingAt {
+    /// Whether the `call`th call (counting from zero) fails.
+    #[inline]
+    #[must_use]
+    pub const fn fails_on(self, call: usize) -> bool {
+        call >= self.0
+    }
+}
+
+impl Pbt for FailingAt {
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`FailingAt` is not a literal").get();
+        match algebraic_index {
+            1 => Self(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `FailingAt`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.0);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<usize>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<usize>()).collect(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    /// Write `chunks` chunks, retrying each up to twice,
+    /// and report whether everything was written.
+    ///
+    /// Returns what was reported alongside how many chunks were actually written.
+    /// The report is deliberately wrong if the final chunk fails.
+    fn buggy_write_all(chunks: usize, fault: FailingAt) -> (bool, usize) {
+        let mut call = 0_usize;
+        let mut written = 0_usize;
+        for _ in 0..chunks {
+            let mut attempts = 0_u8;
+            while fault.fails_on(call) {
+                call = call.checked_add(1).unwrap();
+                attempts = attempts.checked_add(1).unwrap();
+                if attempts > 2 {
+                    return (written.checked_add(1).unwrap() >= chunks, written);
+                }
+            }
+            call = call.checked_add(1).unwrap();
+            written = written.checked_add(1).unwrap();
+        }
+        (true, written)
+    }
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<FailingAt>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<FailingAt> = persist::replay();
+        let () = expected.extend(
+            [
+                0,
+                7_804_948_724_862_110_416,
+                17_108_568_891_541_767_080,
+                14_756_591_828_928_955_088,
+                1,
+                1,
+                10,
+                19,
+                13,
+                0,
+            ]
+            .map(FailingAt),
+        );
+        let generated: Vec<FailingAt> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<FailingAt>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<FailingAt>();
+    }
+
+    #[test]
+    fn minimal_fault_breaking_retries() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&fault: &FailingAt| {
+                    let (reported, written) = buggy_write_all(4, fault);
+                    (reported != (written == 4)).then_some(written)
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((FailingAt(3), 3)),
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Coverage-guided fuzzing, e.g. with `cargo fuzz`, over `Pbt` types.
+//!
+//! Fuzzers mutate raw bytes; this module turns those bytes into a deterministic value:
+//! the bytes choose the seed, and their length chooses the size,
+//! so longer inputs tend to produce larger values.
+//! In a `cargo fuzz` target:
+//! ```rust,ignore
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+//!     pbt::fuzz::check(data, |v: &Vec<u8>| (v.len() > 100).then_some(()));
+//! });
+//! ```
+
+use {
+    crate::{Pbt, arbitrary, size::Size},
+    core::{any, fmt},
+    wyrand::WyRand,
+};
+
+/// Check a property on the value that a fuzzer's input bytes determine.
+///
+/// Unlike `witness`, this doesn't shrink: fuzzers minimize their own inputs.
+///
+/// # Panics
+///
+/// If the property fails (i.e. returns `Some(..)`), so the fuzzer records a crash,
+/// or if `T` is uninstantiable, so a target that can never check anything doesn't pass forever.
+#[inline]
+#[expect(clippy::panic, reason = "fuzzers detect failures as panics")]
+pub fn check<T, Property, Proof>(bytes: &[u8], property: Property)
+where
+    Property: Fn(&T) -> Option<Proof>,
+    Proof: fmt::Debug,
+    T: Pbt,
+{
+    let mut prng = WyRand::new(seed_from_bytes(bytes));
+    let t = match arbitrary::exactly::<T>(Size::new(bytes.len()), &mut prng) {
+        Ok(t) => t,
+        Err(uninstantiable) => panic!("can't fuzz `{}`: {uninstantiable}", any::type_name::<T>()),
+    };
+    if let Some(proof) = property(&t) {
+        panic!("\r\nConsider the following input:\r\n\r\n```\r\n{t:#?}\r\n```\r\n\r\n{proof:#?}");
+    }
+}
+
+/// Deterministically hash bytes into a seed,
+/// identically on every platform.
+///
+/// The length is mixed in, so e.g. `[]`, `[0]`, and `[0, 0]` all differ.
+#[inline]
+#[must_use]
+pub fn seed_from_bytes(bytes: &[u8]) -> u64 {
+    let mut seed = u64::try_from(bytes.len()).unwrap_or(u64::MAX);
+    for chunk in bytes.chunks(8) {
+        let mut bytes_le = [0; 8];
+        for (dst, &src) in bytes_le.iter_mut().zip(chunk) {
+            *dst = src;
+        }
+        #[expect(
+            clippy::little_endian_bytes,
+            reason = "a fixed byte order keeps seeds identical across platforms"
+        )]
+        let word = u64::from_le_bytes(bytes_le);
+        seed = WyRand::new(seed ^ word).rand();
+    }
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, core::convert::Infallible, pretty_assertions::assert_eq};
+
+    /// An example fuzz target, as `cargo fuzz` would call it.
+    fn target(data: &[u8]) {
+        check(data, |v: &Vec<u8>| v.contains(&42).then_some(()));
+    }
+
+    #[test]
+    fn seed_is_deterministic() {
+        let seeds: Vec<u64> = [&[][..], &[0], &[0, 0], b"0123456789"]
+            .into_iter()
+            .map(seed_from_bytes)
+            .collect();
+        let expected: Vec<u64> = vec![
+            0,
+            12_120_239_713_417_281_201,
+            3_736_708_066_431_228_558,
+            17_760_199_651_859_448_329,
+        ];
+        assert_eq!(seeds, expected);
+    }
+
+    #[test]
+    fn seed_distinguishes_lengths() {
+        let mut seeds: Vec<u64> = (0..=16).map(|n| seed_from_bytes(&vec![0; n])).collect();
+        let () = seeds.sort_unstable();
+        let () = seeds.dedup();
+        assert_eq!(seeds.len(), 17);
+    }
+
+    #[test]
+    fn target_accepts_passing_inputs() {
+        let () = target(&[]);
+        let () = target(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Consider the following input")]
+    fn target_reports_failures() {
+        for n in 0_u8..=u8::MAX {
+            let () = target(&[n; 64]);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "can't fuzz `core::convert::Infallible`: type is uninstantiable with finite memory"
+    )]
+    fn uninstantiable_targets_fail() {
+        check(&[], |_: &Infallible| None::<()>);
+    }
+}
@@ -0,0 +1,29 @@
+//! Bespoke generators for types that already implement `Pbt`,
+//! e.g. to focus on a narrower domain than the default distribution.
+
+use wyrand::WyRand;
+
+/// A bespoke source of values of type `T`.
+///
+/// Generators replace only generation:
+/// shrinking still uses `T`'s `Pbt` implementation,
+/// so properties should not assume that
+/// every value they see came from this generator.
+///
+/// Any closure `Fn(&mut WyRand) -> T` is a generator.
+/// The default generator is the one `witness` uses,
+/// so there's no need to name it.
+pub trait Generator<T> {
+    /// Generate one value from a pseudorandom number generator.
+    fn generate(&self, prng: &mut WyRand) -> T;
+}
+
+impl<T, F> Generator<T> for F
+where
+    F: Fn(&mut WyRand) -> T,
+{
+    #[inline]
+    fn generate(&self, prng: &mut WyRand) -> T {
+        self(prng)
+    }
+}
@@ -1,4 +1,7 @@
 //! Implementations for `Arc<_>`.
+//!
+//! Shared aliasing is not modeled:
+//! every generated `Arc` is the unique owner of its contents.
 
 use {
     crate::{
@@ -40,7 +43,7 @@ where
     #[inline]
     fn deconstruct(self) -> Parts<Store> {
         let mut fields = Store::new();
-        let () = fields.push::<T>(T::clone(&self));
+        let () = fields.push::<T>(Arc::unwrap_or_clone(self));
         Parts {
             fields,
             variant_index: Some(const { NonZero::new(1).unwrap() }),
@@ -62,7 +65,10 @@ mod tests {
 
     use {
         super::*,
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -105,4 +111,17 @@ mod tests {
     fn serialization_deep() {
         let () = check_serialization::<Arc<Arc<usize>>>();
     }
+
+    #[test]
+    fn witness_at_least_1000() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |n: &Arc<u32>| n.checked_sub(1000),
+                DEFAULT_N_CASES,
+                &mut prng
+            ),
+            Some((Arc::new(1000), 0)),
+        );
+    }
 }
@@ -62,7 +62,12 @@ mod tests {
     #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
 
     use {
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_eta_expansion,
+            check_serialization, check_shrink_termination, enumerate,
+            reflection::register_globally, shrink, witness,
+        },
+        core::array,
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -86,6 +91,60 @@ mod tests {
         assert_eq!(generated, expected);
     }
 
+    #[test]
+    fn empty() {
+        assert_eq!(cardinality::<[u8; 0]>(), Some(1));
+        let all: Vec<[u8; 0]> = enumerate().unwrap().collect();
+        assert_eq!(all, [[0_u8; 0]]);
+        let () = check_eta_expansion::<[u8; 0]>();
+        let () = check_serialization::<[u8; 0]>();
+    }
+
+    #[test]
+    fn enumerate_bool_pairs() {
+        assert_eq!(cardinality::<[bool; 2]>(), Some(4));
+        let all: Vec<[bool; 2]> = enumerate().unwrap().collect();
+        assert_eq!(
+            all,
+            [[false, false], [false, true], [true, false], [true, true]],
+        );
+    }
+
+    #[test]
+    fn enumerate_lazily() {
+        assert_eq!(cardinality::<[bool; 40]>(), Some(1 << 40_u32));
+        let first: Vec<[bool; 40]> = enumerate().unwrap().take(3).collect();
+        assert_eq!(
+            first,
+            [
+                [false; 40],
+                array::from_fn(|i| i == 39),
+                array::from_fn(|i| i == 38),
+            ],
+        );
+    }
+
+    #[test]
+    fn shrinking_preserves_length() {
+        let () = register_globally::<[u8; 3]>();
+        assert!(shrink::candidates([200_u8, 0, 7]).all(|shrunk| shrunk != [200, 0, 7]));
+        let () = check_shrink_termination(&[200_u8, 0, 7], 100);
+    }
+
+    #[test]
+    fn minimal_sum() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |array: &[u16; 3]| (array.iter().map(|&i| u32::from(i)).sum::<u32>() >= 100)
+                    .then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some(([0, 0, 100], ())),
+        );
+    }
+
     #[test]
     fn eta_expansion() {
         let () = check_eta_expansion::<[usize; 3]>();
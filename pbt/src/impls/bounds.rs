@@ -0,0 +1,139 @@
+//! Implementations for `Bound<_>`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero, ops::Bound},
+};
+
+impl<T> Pbt for Bound<T>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Bound` is not a literal").get();
+        match algebraic_index {
+            1 => Self::Unbounded,
+            2 => Self::Included(fields.field()),
+            3 => Self::Excluded(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Bound`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let variant_index = match self {
+            Self::Unbounded => const { NonZero::new(1).unwrap() },
+            Self::Included(t) => {
+                let () = fields.push(t);
+                const { NonZero::new(2).unwrap() }
+            }
+            Self::Excluded(t) => {
+                let () = fields.push(t);
+                const { NonZero::new(3).unwrap() }
+            }
+        };
+        Parts {
+            fields,
+            variant_index: Some(variant_index),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: Multiset::new(),
+            },
+            Variant {
+                field_types: iter::once(TypeId::of::<T>()).collect(),
+            },
+            Variant {
+                field_types: iter::once(TypeId::of::<T>()).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_enumeration, check_eta_expansion,
+            check_serialization, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Bound<usize>> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Bound<usize>> = vec![
+            Bound::Included(0),
+            Bound::Excluded(7_804_948_724_862_110_416),
+            Bound::Excluded(17_108_568_891_541_767_080),
+            Bound::Unbounded,
+            Bound::Unbounded,
+            Bound::Unbounded,
+            Bound::Excluded(3_455_211_640_292_790_292),
+            Bound::Excluded(10),
+            Bound::Excluded(6_576_892_163_881_213_418),
+            Bound::Excluded(9_271_126_992_018_358_126),
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration() {
+        let () = check_enumeration::<Bound<bool>>();
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Bound<usize>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Bound<usize>>();
+    }
+
+    #[test]
+    fn minimal_excluded() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |bound: &Bound<bool>| matches!(*bound, Bound::Excluded(_)).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Bound::Excluded(false), ())),
+        );
+    }
+}
@@ -1,4 +1,4 @@
-//! Implementations for `Box<_>`.
+//! Implementations for `Box<_>` and boxed slices `Box<[_]>`.
 
 use {
     crate::{
@@ -55,12 +55,61 @@ where
     }
 }
 
+impl<T> Pbt for Box<[T]>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Box<[_]>` is not a literal").get();
+        match algebraic_index {
+            1 => fields.field::<Vec<T>>().into_boxed_slice(),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Box<[_]>`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push::<Vec<T>>(self.into_vec());
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<Vec<T>>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<Vec<T>>()).collect(),
+        }])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
 
     use {
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        core::iter,
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -103,4 +152,38 @@ mod tests {
     fn serialization_deep() {
         let () = check_serialization::<Box<Box<usize>>>();
     }
+
+    #[test]
+    fn slice_deterministic() {
+        let () = register_globally::<Box<[u8]>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Box<[u8]>> = persist::replay();
+        let () = expected.extend(iter::repeat_n(Box::default(), 10));
+        let generated: Vec<Box<[u8]>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn slice_eta_expansion() {
+        let () = check_eta_expansion::<Box<[u8]>>();
+    }
+
+    #[test]
+    fn slice_serialization() {
+        let () = check_serialization::<Box<[u8]>>();
+    }
+
+    #[test]
+    fn minimal_slice_with_nonzero_sum() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness::<Box<[u8]>, _, _>(
+                |slice| { (slice.len() >= 2 && slice.iter().any(|&byte| byte != 0)).then_some(()) },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((vec![0, 1].into_boxed_slice(), ())),
+        );
+    }
 }
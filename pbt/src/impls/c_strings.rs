@@ -0,0 +1,145 @@
+//! Implementations for `CString`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    alloc::ffi::CString,
+    core::{any::TypeId, num::NonZero},
+};
+
+impl Pbt for CString {
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`CString` is not a literal").get();
+        match algebraic_index {
+            1 => Self::default(),
+            2 => {
+                let init: Self = fields.field();
+                let caboose: u8 = fields.field();
+                if caboose == 0 {
+                    // Interior NULs are impossible, so skip them.
+                    return init;
+                }
+                let mut acc = init.into_bytes();
+                let () = acc.push(caboose);
+                Self::new(acc).expect("INTERNAL ERROR (`pbt`): interior NUL in a `CString`")
+            }
+            _ => panic!("can't instantiate variant #{algebraic_index} of `CString`"),
+        }
+    }
+
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        reason = "Internal invariants: violations should fail loudly."
+    )]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut bytes = self.into_bytes();
+        let Some(caboose) = bytes.pop() else {
+            return Parts {
+                fields: Store::new(),
+                variant_index: Some(const { NonZero::new(1).unwrap() }),
+            };
+        };
+        let init = Self::new(bytes).expect("INTERNAL ERROR (`pbt`): interior NUL in a `CString`");
+        let mut fields = Store::new();
+        let () = fields.push(caboose);
+        let () = fields.push(init);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(2).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<u8>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: Multiset::new(),
+            },
+            Variant {
+                field_types: [TypeId::of::<Self>(), TypeId::of::<u8>()]
+                    .into_iter()
+                    .collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<CString>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<CString> = persist::replay();
+        let () = expected.extend([
+            c"".to_owned(),
+            c"".to_owned(),
+            c"".to_owned(),
+            c"".to_owned(),
+            c"\x9cn".to_owned(),
+            c"'\xd3".to_owned(),
+            c"\x04".to_owned(),
+            c"".to_owned(),
+            c"".to_owned(),
+            c"\x02".to_owned(),
+        ]);
+        let generated: Vec<CString> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<CString>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<CString>();
+    }
+
+    #[test]
+    fn minimal_non_ascii() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |s: &CString| s.as_bytes().iter().find(|byte| !byte.is_ascii()).copied(),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((CString::new([0x80]).unwrap(), 0x80)),
+        );
+    }
+}
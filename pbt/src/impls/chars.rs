@@ -1,4 +1,4 @@
-//! Implementations for built-in fixed-width integer types like `u8`, `isize`, etc.
+//! Implementations for `char`.
 
 use {
     crate::{
@@ -11,6 +11,9 @@ use {
     wyrand::WyRand,
 };
 
+/// The simplest `char`s, simplest first, tried before any other shrinking.
+const SIMPLEST: [char; 3] = ['a', '0', ' '];
+
 impl Pbt for char {
     #[inline]
     fn construct<F>(
@@ -52,22 +55,28 @@ impl Pbt for char {
     }
 }
 
-/// Shrink a `char` by repeatedly subtracting half the previous shrunk amount.
+/// Shrink a `char` by first trying each simpler entry in `SIMPLEST`,
+/// then (unless it's in `SIMPLEST` already)
+/// repeatedly subtracting half the previous shrunk amount,
+/// skipping any code point in the surrogate range `0xD800..=0xDFFF`.
 #[inline]
 fn shrink(c: char) -> Box<dyn Iterator<Item = char>> {
+    if let Some(rank) = SIMPLEST.iter().position(|&simple| simple == c) {
+        return Box::new(SIMPLEST.into_iter().take(rank));
+    }
     let n = u32::from(c);
     let mut shift = 0;
-    Box::new(
-        iter::from_fn(move || {
-            let delta = n.checked_shr(shift)?;
-            if delta == 0 {
-                return None;
-            }
-            shift = shift.checked_add(1)?;
-            n.checked_sub(delta)
-        })
-        .filter_map(|u32| char::try_from(u32).ok()),
-    )
+    let halving = iter::from_fn(move || {
+        let delta = n.checked_shr(shift)?;
+        if delta == 0 {
+            return None;
+        }
+        shift = shift.checked_add(1)?;
+        n.checked_sub(delta)
+    })
+    .filter_map(|u32| char::try_from(u32).ok())
+    .filter(|shrunk| !SIMPLEST.contains(shrunk));
+    Box::new(SIMPLEST.into_iter().chain(halving))
 }
 
 /// Generate integers uniformly over the target machine word.
@@ -93,16 +102,20 @@ mod tests {
 
     use {
         super::*,
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
 
     #[test]
     fn deterministic() {
+        let () = register_globally::<char>();
         let mut prng = WyRand::new(42);
-        let generated: Vec<char> = arbitrary(&mut prng).unwrap().take(10).collect();
-        let expected: Vec<char> = vec![
+        let mut expected: Vec<char> = persist::replay();
+        let () = expected.extend([
             '\u{fb8e8}',
             '\u{9bf28}',
             '\u{7ea5b}',
@@ -113,13 +126,17 @@ mod tests {
             '\u{f7975}',
             '\u{8a8c1}',
             '\u{fdc56}',
-        ];
+        ]);
+        let generated: Vec<char> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
         assert_eq!(generated, expected);
     }
 
     #[test]
     fn deterministic_shrink() {
         let mut iter = shrink('z');
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next(), Some('0'));
+        assert_eq!(iter.next(), Some(' '));
         assert_eq!(iter.next(), Some('\0'));
         assert_eq!(iter.next(), Some('='));
         assert_eq!(iter.next(), Some('\\'));
@@ -130,6 +147,39 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn simplest_shrink_only_to_simpler() {
+        assert_eq!(shrink('a').collect::<Vec<_>>(), []);
+        assert_eq!(shrink('0').collect::<Vec<_>>(), ['a']);
+        assert_eq!(shrink(' ').collect::<Vec<_>>(), ['a', '0']);
+    }
+
+    #[test]
+    fn shrink_skips_surrogates() {
+        // Halving from `'\u{e800}'` lands on the surrogate `0xD980`, which is skipped:
+        assert_eq!(
+            shrink('\u{e800}').collect::<Vec<_>>(),
+            [
+                'a', '0', ' ', '\u{0}', '\u{7400}', '\u{ae00}', '\u{cb00}', '\u{e0c0}', '\u{e460}',
+                '\u{e630}', '\u{e718}', '\u{e78c}', '\u{e7c6}', '\u{e7e3}', '\u{e7f2}', '\u{e7f9}',
+                '\u{e7fd}', '\u{e7ff}',
+            ],
+        );
+    }
+
+    #[test]
+    fn minimal_non_alphanumeric() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&c: &char| (!c.is_alphanumeric()).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((' ', ())),
+        );
+    }
+
     #[test]
     fn eta_expansion() {
         let () = check_eta_expansion::<char>();
@@ -0,0 +1,141 @@
+//! Implementations for `ControlFlow<_, _>`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero, ops::ControlFlow},
+};
+
+impl<B, C> Pbt for ControlFlow<B, C>
+where
+    B: Pbt,
+    C: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`ControlFlow` is not a literal").get();
+        match algebraic_index {
+            1 => Self::Continue(fields.field()),
+            2 => Self::Break(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `ControlFlow`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let variant_index = match self {
+            Self::Continue(c) => {
+                let () = fields.push(c);
+                const { NonZero::new(1).unwrap() }
+            }
+            Self::Break(b) => {
+                let () = fields.push(b);
+                const { NonZero::new(2).unwrap() }
+            }
+        };
+        Parts {
+            fields,
+            variant_index: Some(variant_index),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<C>();
+        let () = registration.register::<B>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: iter::once(TypeId::of::<C>()).collect(),
+            },
+            Variant {
+                field_types: iter::once(TypeId::of::<B>()).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<ControlFlow<usize, usize>> =
+            arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<ControlFlow<usize, usize>> = vec![
+            ControlFlow::Break(17_850_812_975_400_668_360),
+            ControlFlow::Continue(13_639_797_723_846_260_844),
+            ControlFlow::Continue(0),
+            ControlFlow::Continue(14_559_301_688_031_195_406),
+            ControlFlow::Continue(0),
+            ControlFlow::Continue(3),
+            ControlFlow::Break(1_501_726_134_688_862_675),
+            ControlFlow::Break(3),
+            ControlFlow::Continue(0),
+            ControlFlow::Break(5_252_106_543_385_571_987),
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<ControlFlow<usize, bool>>();
+    }
+
+    #[test]
+    fn eta_expansion_deep() {
+        let () = check_eta_expansion::<ControlFlow<ControlFlow<bool, usize>, usize>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<ControlFlow<usize, bool>>();
+    }
+
+    #[test]
+    fn serialization_deep() {
+        let () = check_serialization::<ControlFlow<ControlFlow<bool, usize>, usize>>();
+    }
+
+    #[test]
+    fn minimal_break() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |flow: &ControlFlow<u8, bool>| flow.break_value()?.checked_sub(10),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((ControlFlow::Break(10), 0)),
+        );
+    }
+}
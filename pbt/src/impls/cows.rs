@@ -0,0 +1,160 @@
+//! Implementations for `Cow<'static, [_]>`.
+//!
+//! Only the empty slice is `'static` for every `T`,
+//! so that's the only value ever `Cow::Borrowed`.
+//! Any other borrowed slice deconstructs into an owned `Vec`,
+//! so shrinking it yields only `Cow::Owned` values.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    alloc::borrow::Cow,
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+impl<T> Pbt for Cow<'static, [T]>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Cow` is not a literal").get();
+        match algebraic_index {
+            1 => Cow::Borrowed(&[]),
+            2 => Cow::Owned(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Cow`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        if let Cow::Borrowed(&[]) = self {
+            return Parts {
+                fields: Store::new(),
+                variant_index: Some(const { NonZero::new(1).unwrap() }),
+            };
+        }
+        let mut fields = Store::new();
+        let () = fields.push(self.into_owned());
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(2).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<Vec<T>>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: Multiset::new(),
+            },
+            Variant {
+                field_types: iter::once(TypeId::of::<Vec<T>>()).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, shrink, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<Cow<'static, [u8]>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Cow<'static, [u8]>> = persist::replay();
+        let () = expected.extend::<[Cow<'static, [u8]>; _]>([
+            Cow::Owned(vec![]),
+            Cow::Owned(vec![]),
+            Cow::Borrowed(&[]),
+            Cow::Borrowed(&[]),
+            Cow::Owned(vec![]),
+            Cow::Owned(vec![]),
+            Cow::Owned(vec![239]),
+            Cow::Owned(vec![]),
+            Cow::Owned(vec![148]),
+            Cow::Owned(vec![205]),
+        ]);
+        let generated: Vec<Cow<'static, [u8]>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Cow<'static, [u8]>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Cow<'static, [u8]>>();
+    }
+
+    #[test]
+    fn generates_both_variants() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Cow<'static, [u8]>> = arbitrary(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .collect();
+        assert!(generated.iter().any(|cow| matches!(*cow, Cow::Borrowed(_))));
+        assert!(generated.iter().any(|cow| matches!(*cow, Cow::Owned(_))));
+    }
+
+    #[test]
+    fn shrinking_borrowed_yields_owned() {
+        static BORROWED: [u8; 2] = [1, 2];
+        let () = register_globally::<Cow<'static, [u8]>>();
+        let candidates: Vec<Cow<'static, [u8]>> =
+            shrink::candidates(Cow::Borrowed(&BORROWED[..])).collect();
+        assert!(!candidates.is_empty());
+        assert!(
+            candidates
+                .iter()
+                .all(|cow| matches!(*cow, Cow::Owned(_)) || cow.is_empty())
+        );
+    }
+
+    #[test]
+    fn minimal_pair() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |cow: &Cow<'static, [u8]>| (cow.len() >= 2).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Cow::Owned(vec![0, 0]), ())),
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Implementations for `core::time::Duration`.
+
+use {
+    crate::{
+        Pbt,
+        coin_flips::CoinFlips,
+        fields::{Fields, Store},
+        impls::shrink,
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::{iter, time::Duration},
+    wyrand::WyRand,
+};
+
+/// Nanoseconds per second.
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+impl Pbt for Duration {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Duration` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                from_nanos(s.parse().ok()?)
+            },
+            generators: vec![edge_case, small, uniform],
+            serialize: |d| d.as_nanos().to_string().into(),
+            shrink,
+        }
+    }
+}
+
+/// Choose a duration likely to trip up arithmetic on durations.
+#[inline]
+fn edge_case(prng: &mut WyRand) -> Duration {
+    const EDGE_CASES: [Duration; 6] = [
+        Duration::ZERO,
+        Duration::MAX,
+        Duration::from_nanos(1),
+        Duration::from_secs(1),
+        Duration::from_secs(u64::MAX),
+        Duration::new(0, 999_999_999),
+    ];
+    modulo::pick(prng, &EDGE_CASES)
+}
+
+/// The duration with exactly this many nanoseconds, if it's representable.
+///
+/// Splitting a single count into seconds and nanoseconds, rather than
+/// adjusting each separately, can't produce an invalid `Duration`.
+#[inline]
+fn from_nanos(total: u128) -> Option<Duration> {
+    let per_sec = u128::from(NANOS_PER_SEC);
+    let secs = u64::try_from(total.checked_div(per_sec)?).ok()?;
+    let nanos = u32::try_from(total.checked_rem(per_sec)?).ok()?;
+    Some(Duration::new(secs, nanos))
+}
+
+/// Shrink toward `Duration::ZERO` by repeatedly subtracting
+/// half the previous shrunk amount of nanoseconds.
+#[inline]
+fn shrink(d: Duration) -> Box<dyn Iterator<Item = Duration>> {
+    Box::new((shrink!(u128))(d.as_nanos()).filter_map(from_nanos))
+}
+
+/// Generate short durations: a few seconds plus a few nanoseconds.
+#[inline]
+fn small(prng: &mut WyRand) -> Duration {
+    let mut coin = CoinFlips::new(prng);
+    let mut secs = 0_u64;
+    while coin.flip(prng) {
+        secs = secs.wrapping_shl(1) | u64::from(coin.flip(prng));
+    }
+    let mut nanos = 0_u32;
+    while coin.flip(prng) {
+        nanos = nanos.wrapping_shl(1) | u32::from(coin.flip(prng));
+    }
+    Duration::new(secs, nanos.checked_rem(NANOS_PER_SEC).unwrap_or(0))
+}
+
+/// Generate durations uniformly over seconds and, separately, nanoseconds.
+#[inline]
+#[expect(
+    clippy::expect_used,
+    reason = "Internal invariants: violations should fail loudly."
+)]
+fn uniform(prng: &mut WyRand) -> Duration {
+    let secs = prng.rand();
+    let nanos = prng
+        .rand()
+        .checked_rem(u64::from(NANOS_PER_SEC))
+        .unwrap_or(0);
+    Duration::new(
+        secs,
+        u32::try_from(nanos).expect("INTERNAL ERROR (`pbt`): nanoseconds out of range"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            check_shrink_termination, persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<Duration>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Duration> = persist::replay();
+        let () = expected.extend([
+            Duration::from_secs(1),
+            Duration::new(0, 999_999_999),
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::from_nanos(1),
+            Duration::from_nanos(1),
+            Duration::from_nanos(1),
+            Duration::new(7_818_439_755_390_442_116, 400_878_194),
+            Duration::ZERO,
+            Duration::new(111_264_653_324_281_611, 63_199_176),
+        ]);
+        let generated: Vec<Duration> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Duration>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Duration>();
+    }
+
+    #[test]
+    fn shrink_from_max_borrows_across_seconds() {
+        assert_eq!(Duration::MAX, Duration::new(u64::MAX, 999_999_999));
+        let shrunk: Vec<Duration> = shrink(Duration::MAX).collect();
+        assert_eq!(shrunk.first(), Some(&Duration::ZERO));
+        assert_eq!(shrunk.last(), Some(&Duration::new(u64::MAX, 999_999_998)));
+        assert!(
+            shrunk
+                .iter()
+                .all(|d| *d < Duration::MAX && d.subsec_nanos() < NANOS_PER_SEC)
+        );
+        let () = check_shrink_termination(&Duration::MAX, 200);
+    }
+
+    #[test]
+    fn minimal_over_a_second() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |d: &Duration| (*d > Duration::from_secs(1)).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Duration::new(1, 1), ())),
+        );
+    }
+}
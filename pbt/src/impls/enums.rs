@@ -0,0 +1,70 @@
+//! Helpers for field-less (C-like) `enum`s.
+//!
+//! Standard-library `enum`s covered this way:
+//! - `core::cmp::Ordering`
+//! - `core::num::FpCategory`
+//! - `core::sync::atomic::Ordering`
+//! - `std::net::Shutdown`
+
+/// Implement `Pbt` for a field-less `enum`,
+/// numbering variants from 1 in the order listed,
+/// so shrinking moves toward the first variant listed.
+///
+/// Expects `Fields`, `Multiset`, `NonZero`, `Parts`, `Pbt`,
+/// `Registration`, `Store`, `Variant`, and `Variants` in scope.
+macro_rules! impl_unit_enum {
+    ($ty:ident { $($variant:ident),+ $(,)? }) => {
+        impl Pbt for $ty {
+            #[inline]
+            #[allow(
+                clippy::allow_attributes,
+                clippy::expect_used,
+                clippy::panic,
+                reason = "end-users shouldn't be calling this"
+            )]
+            fn construct<F>(Parts { variant_index, .. }: Parts<F>) -> Self
+            where
+                F: Fields,
+            {
+                let algebraic_index: usize =
+                    variant_index.expect(concat!("`", stringify!($ty), "` is not a literal")).get();
+                algebraic_index
+                    .checked_sub(1)
+                    .and_then(|i| [$($ty::$variant),+].get(i).cloned())
+                    .unwrap_or_else(|| panic!(
+                        concat!("can't instantiate variant #{} of `", stringify!($ty), "`"),
+                        algebraic_index,
+                    ))
+            }
+
+            #[inline]
+            #[allow(
+                clippy::allow_attributes,
+                clippy::expect_used,
+                reason = "Internal invariants: violations should fail loudly."
+            )]
+            fn deconstruct(self) -> Parts<Store> {
+                let discriminant = core::mem::discriminant(&self);
+                let index = [$($ty::$variant),+]
+                    .iter()
+                    .position(|variant| core::mem::discriminant(variant) == discriminant)
+                    .and_then(|i| NonZero::<usize>::MIN.checked_add(i))
+                    .expect(concat!("INTERNAL ERROR (`pbt`): unlisted variant of `", stringify!($ty), "`"));
+                Parts {
+                    fields: Store::new(),
+                    variant_index: Some(index),
+                }
+            }
+
+            #[inline]
+            fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+                Variants::Algebraic(vec![$({
+                    let _: $ty = $ty::$variant;
+                    Variant {
+                        field_types: Multiset::new(),
+                    }
+                }),+])
+            }
+        }
+    };
+}
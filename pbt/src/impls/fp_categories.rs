@@ -0,0 +1,95 @@
+//! Implementations for `core::num::FpCategory`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::num::{FpCategory, NonZero},
+};
+
+impl_unit_enum!(FpCategory {
+    Nan,
+    Infinite,
+    Zero,
+    Subnormal,
+    Normal,
+});
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            enumerate, persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<FpCategory>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<FpCategory> = persist::replay();
+        let () = expected.extend([
+            FpCategory::Nan,
+            FpCategory::Subnormal,
+            FpCategory::Normal,
+            FpCategory::Zero,
+            FpCategory::Subnormal,
+            FpCategory::Infinite,
+            FpCategory::Nan,
+            FpCategory::Subnormal,
+            FpCategory::Infinite,
+            FpCategory::Nan,
+        ]);
+        let generated: Vec<FpCategory> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration() {
+        let all: Vec<FpCategory> = enumerate().unwrap().collect();
+        assert_eq!(
+            all,
+            [
+                FpCategory::Nan,
+                FpCategory::Infinite,
+                FpCategory::Zero,
+                FpCategory::Subnormal,
+                FpCategory::Normal,
+            ],
+        );
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<FpCategory>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<FpCategory>();
+    }
+
+    #[test]
+    fn shrinks_toward_first_variant() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&category: &FpCategory| (category != FpCategory::Nan).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((FpCategory::Infinite, ())),
+        );
+    }
+}
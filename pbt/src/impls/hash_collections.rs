@@ -158,7 +158,9 @@ mod tests {
 
     use {
         super::*,
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization, sample,
+        },
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -230,6 +232,16 @@ mod tests {
         assert_eq!(generated, expected);
     }
 
+    #[test]
+    fn degenerate_unit_keys() {
+        let mut prng = WyRand::new(42);
+        let maps: Vec<HashMap<(), u8>> = sample(DEFAULT_N_CASES, &mut prng);
+        assert!(maps.iter().all(|map| map.len() <= 1));
+        assert!(maps.iter().any(|map| map.len() == 1));
+        let () = check_eta_expansion::<HashMap<(), u8>>();
+        let () = check_serialization::<HashMap<(), u8>>();
+    }
+
     #[test]
     fn eta_expansion_map() {
         let () = check_eta_expansion::<HashMap<usize, usize>>();
@@ -46,6 +46,50 @@ macro_rules! small {
     };
 }
 
+/// Generate small signed integers: a small magnitude (as in `small!`) with a random sign.
+macro_rules! small_signed {
+    ($i:ty, $u:ty) => {
+        |prng| {
+            let mut coin = CoinFlips::new(prng);
+            if coin.flip(prng) {
+                return 0;
+            }
+            let negative = coin.flip(prng);
+            let mut acc: $u = 1;
+            while coin.flip(prng) {
+                acc = acc.wrapping_shl(1) | <$u>::from(coin.flip(prng));
+            }
+            if negative {
+                <$i>::try_from(acc).map_or(<$i>::MIN, <$i>::wrapping_neg)
+            } else {
+                <$i>::try_from(acc).unwrap_or(<$i>::MAX)
+            }
+        }
+    };
+}
+
+/// Shrink a signed integer toward zero by magnitude, preferring positive values.
+///
+/// Never negates `MIN` (which would overflow):
+/// magnitudes are computed with `unsigned_abs`,
+/// and only strictly smaller magnitudes are ever negated.
+macro_rules! shrink_signed {
+    ($i:ty, $u:ty) => {
+        |n: $i| {
+            let negative = n.is_negative();
+            Box::new(n.checked_neg().filter(|_| negative).into_iter().chain(
+                (shrink!($u))(n.unsigned_abs()).flat_map(move |magnitude| {
+                    let positive = <$i>::try_from(magnitude).ok();
+                    let negated = positive
+                        .filter(|&p| negative && p.is_positive())
+                        .and_then(<$i>::checked_neg);
+                    positive.into_iter().chain(negated)
+                }),
+            ))
+        }
+    };
+}
+
 /// Implement `Pbt` for `u_` up to `u64`, above which we need another strategy.
 macro_rules! impl_unsigned {
     ($u:ty) => {
@@ -103,6 +147,65 @@ macro_rules! impl_unsigned {
     };
 }
 
+/// Implement `Pbt` for `i_`, given the unsigned type of the same width
+/// and (optionally) a uniform generator.
+macro_rules! impl_signed {
+    ($i:ty, $u:ty) => {
+        impl_signed!($i, $u, |prng| {
+            #[allow(
+                clippy::allow_attributes,
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                clippy::cast_possible_wrap,
+                reason = "intentional: keep only the low bits"
+            )]
+            (prng.rand() as $i)
+        });
+    };
+    ($i:ty, $u:ty, $uniform:expr) => {
+        impl Pbt for $i {
+            #[inline]
+            fn construct<F>(
+                Parts {
+                    mut fields,
+                    variant_index,
+                }: Parts<F>,
+            ) -> Self
+            where
+                F: Fields,
+            {
+                debug_assert_eq!(variant_index, None, "signed integers are literals");
+                fields.field()
+            }
+
+            #[inline]
+            fn deconstruct(self) -> Parts<Store> {
+                let mut fields = Store::new();
+                let () = fields.push(self);
+                Parts {
+                    fields,
+                    variant_index: None,
+                }
+            }
+
+            #[inline]
+            fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+                Variants::Literal {
+                    deserialize: |json| {
+                        let serde_json::Value::String(ref s) = *json else {
+                            return None;
+                        };
+                        s.parse().ok()
+                    },
+                    generators: vec![$uniform, small_signed!($i, $u)],
+                    serialize: |&i| i.to_string().into(),
+                    shrink: shrink_signed!($i, $u),
+                }
+            }
+        }
+    };
+}
+
 impl_unsigned!(u8);
 impl_unsigned!(u16);
 impl_unsigned!(u32);
@@ -149,6 +252,12 @@ impl Pbt for usize {
     }
 }
 
+impl_signed!(i8, u8);
+impl_signed!(i16, u16);
+impl_signed!(i32, u32);
+impl_signed!(i64, u64);
+impl_signed!(isize, usize, |prng| uniform(prng).cast_signed());
+
 #[cfg(feature = "num-bigint")]
 impl Pbt for num_bigint::BigUint {
     #[inline]
@@ -230,7 +339,7 @@ pub(crate) fn big_uint(
 
 /// Generate integers uniformly over the target machine word.
 #[inline]
-fn uniform(prng: &mut WyRand) -> usize {
+pub(super) fn uniform(prng: &mut WyRand) -> usize {
     if const { usize::BITS <= 64 } {
         #[expect(
             clippy::as_conversions,
@@ -262,8 +371,8 @@ mod tests {
     use {
         super::*,
         crate::{
-            arbitrary::arbitrary, check_eta_expansion, check_serialization, persist,
-            reflection::register_globally,
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            check_shrink_termination, persist, reflection::register_globally, shrink, witness,
         },
         pretty_assertions::assert_eq,
         wyrand::WyRand,
@@ -318,6 +427,75 @@ mod tests {
         let () = check_serialization::<usize>();
     }
 
+    #[test]
+    fn deterministic_i8() {
+        let () = register_globally::<i8>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<i8> = persist::replay();
+        let () = expected.extend([-1, -3, -3, -26, 88, -88, -2, 0, 1, 0]);
+        let generated: Vec<i8> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn i8_eta_expansion() {
+        let () = check_eta_expansion::<i8>();
+    }
+
+    #[test]
+    fn i8_serialization() {
+        let () = check_serialization::<i8>();
+    }
+
+    #[test]
+    fn isize_eta_expansion() {
+        let () = check_eta_expansion::<isize>();
+    }
+
+    #[test]
+    fn isize_serialization() {
+        let () = check_serialization::<isize>();
+    }
+
+    #[test]
+    fn deterministic_signed_shrink() {
+        let () = register_globally::<i8>();
+        let shrunk: Vec<i8> = shrink::candidates(-10_i8).collect();
+        let expected: Vec<i8> = vec![10, 0, 5, -5, 8, -8, 9, -9];
+        assert_eq!(shrunk, expected);
+    }
+
+    #[test]
+    fn shrinking_min_converges_toward_zero() {
+        let () = register_globally::<i8>();
+        let () = register_globally::<i16>();
+        let () = register_globally::<i32>();
+        let () = register_globally::<i64>();
+        assert_eq!(shrink::candidates(i8::MIN).next(), Some(0));
+        assert_eq!(shrink::candidates(i16::MIN).next(), Some(0));
+        assert_eq!(shrink::candidates(i32::MIN).next(), Some(0_i32));
+        assert_eq!(shrink::candidates(i64::MIN).next(), Some(0));
+        assert!(shrink::candidates(i8::MIN).all(|i| i != i8::MIN));
+        assert_eq!(shrink::candidates(i8::MIN).last(), Some(-i8::MAX));
+        let () = check_shrink_termination(&i8::MIN, 1_000);
+        let () = check_shrink_termination(&i16::MIN, 1_000);
+        let () = check_shrink_termination(&i32::MIN, 1_000);
+        let () = check_shrink_termination(&i64::MIN, 1_000);
+    }
+
+    #[test]
+    fn minimal_negative() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&i: &i8| (i < -10).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng
+            ),
+            Some((-11, ())),
+        );
+    }
+
     #[test]
     fn deterministic_uniform() {
         let mut prng = WyRand::new(42);
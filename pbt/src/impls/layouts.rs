@@ -0,0 +1,231 @@
+//! Implementations for `core::alloc::Layout`.
+//!
+//! Every `Layout` is valid by construction,
+//! so generation and shrinking only ever choose
+//! a power-of-two alignment and a size that can't overflow `isize`
+//! once rounded up to a multiple of that alignment.
+
+use {
+    crate::{
+        Pbt,
+        coin_flips::CoinFlips,
+        fields::{Fields, Store},
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::{alloc::Layout, iter, num::NonZero},
+    wyrand::WyRand,
+};
+
+impl Pbt for Layout {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Layout` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::Array(ref json_pair) = *json else {
+                    return None;
+                };
+                let [ref json_size, ref json_align] = **json_pair else {
+                    return None;
+                };
+                let size = usize::try_from(json_size.as_u64()?).ok()?;
+                let align = usize::try_from(json_align.as_u64()?).ok()?;
+                Layout::from_size_align(size, align).ok()
+            },
+            generators: vec![edge_case, small, uniform],
+            serialize: |layout| [layout.size(), layout.align()].into_iter().collect(),
+            shrink,
+        }
+    }
+}
+
+/// The largest size that, rounded up to a multiple of `align`, still fits in an `isize`.
+#[inline]
+fn max_size(align: usize) -> usize {
+    isize::MAX
+        .unsigned_abs()
+        .saturating_sub(align.saturating_sub(1))
+}
+
+/// Choose a layout likely to trip up allocators:
+/// zero-sized, a single byte, or the largest valid size or alignment.
+#[inline]
+#[expect(
+    clippy::expect_used,
+    reason = "Internal invariants: violations should fail loudly."
+)]
+fn edge_case(prng: &mut WyRand) -> Layout {
+    let max_align = 1_usize
+        .checked_shl(usize::BITS.saturating_sub(1))
+        .expect("INTERNAL ERROR (`pbt`): `usize` has no bits");
+    let edge_cases = [(0, 1), (1, 1), (max_size(1), 1), (0, max_align)];
+    let (size, align) = modulo::pick(prng, &edge_cases);
+    Layout::from_size_align(size, align).expect("INTERNAL ERROR (`pbt`): invalid edge case")
+}
+
+/// Shrink toward a zero-sized, byte-aligned layout:
+/// first lower the alignment (which only ever allows larger sizes),
+/// then shrink the size as an integer (which only ever shrinks the rounded-up size).
+#[inline]
+fn shrink(layout: Layout) -> Box<dyn Iterator<Item = Layout>> {
+    let size = layout.size();
+    let align = layout.align();
+    let zero = Layout::new::<()>();
+    let lower_aligns = iter::successors(Some(align), |&a| (a > 1).then_some(a >> 1_u32))
+        .skip(1)
+        .filter_map(move |a| Layout::from_size_align(size, a).ok());
+    let smaller_sizes =
+        (shrink!(usize))(size).filter_map(move |s| Layout::from_size_align(s, align).ok());
+    Box::new(
+        iter::once(zero)
+            .filter(move |_| layout != zero)
+            .chain(lower_aligns)
+            .chain(smaller_sizes),
+    )
+}
+
+/// Generate small sizes with small alignments, e.g. those of ordinary types.
+#[inline]
+#[expect(
+    clippy::expect_used,
+    reason = "Internal invariants: violations should fail loudly."
+)]
+fn small(prng: &mut WyRand) -> Layout {
+    let mut coin = CoinFlips::new(prng);
+    let mut align = 1_usize;
+    while align < 4_096 && coin.flip(prng) {
+        align <<= 1_u32;
+    }
+    let mut size = 0_usize;
+    while size < 4_096 && coin.flip(prng) {
+        size = size.wrapping_shl(1) | usize::from(coin.flip(prng));
+    }
+    Layout::from_size_align(size, align).expect("INTERNAL ERROR (`pbt`): invalid small layout")
+}
+
+/// Choose an alignment uniformly among powers of two,
+/// then a size uniformly among those valid for that alignment.
+#[inline]
+#[expect(
+    clippy::expect_used,
+    reason = "Internal invariants: violations should fail loudly."
+)]
+fn uniform(prng: &mut WyRand) -> Layout {
+    let exponents = NonZero::new(usize::BITS).expect("INTERNAL ERROR (`pbt`): `usize` has no bits");
+    let exponent = modulo::below(
+        prng,
+        NonZero::<usize>::try_from(exponents).expect("INTERNAL ERROR (`pbt`): too many bits"),
+    );
+    let align = 1_usize
+        .checked_shl(u32::try_from(exponent).expect("INTERNAL ERROR (`pbt`): too many bits"))
+        .expect("INTERNAL ERROR (`pbt`): alignment overflow");
+    let size = modulo::below(prng, NonZero::<usize>::MIN.saturating_add(max_size(align)));
+    Layout::from_size_align(size, align).expect("INTERNAL ERROR (`pbt`): invalid uniform layout")
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            check_shrink_termination, persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<Layout>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Layout> = persist::replay();
+        let () = expected.extend(
+            [
+                (1, 2),
+                (1, 1),
+                (0, 1),
+                (1, 1),
+                (0, 1),
+                (0, 1),
+                (1, 1),
+                (6_873_997_555_546_102_401, 16),
+                (0, 1),
+                (5_842_619_814_208_425_415, 2_048),
+            ]
+            .map(|(size, align)| Layout::from_size_align(size, align).unwrap()),
+        );
+        let generated: Vec<Layout> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Layout>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Layout>();
+    }
+
+    #[test]
+    fn shrinking_stays_valid() {
+        let largest = Layout::from_size_align(max_size(1), 1).unwrap();
+        let () = check_shrink_termination(&largest, 1_000);
+        let most_aligned = Layout::from_size_align(0, 1 << (usize::BITS - 1)).unwrap();
+        let () = check_shrink_termination(&most_aligned, 1_000);
+    }
+
+    #[test]
+    fn from_size_align_always_succeeds() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |layout: &Layout| Layout::from_size_align(layout.size(), layout.align()).err(),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn minimal_padded() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |layout: &Layout| (layout.pad_to_align() != *layout).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Layout::from_size_align(1, 2).unwrap(), ())),
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Implementations for `LinkedList<_>`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    alloc::collections::LinkedList,
+    core::{any::TypeId, num::NonZero},
+};
+
+impl<T> Pbt for LinkedList<T>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`LinkedList` is not a literal").get();
+        match algebraic_index {
+            1 => Self::new(),
+            2 => {
+                let mut acc: Self = fields.field();
+                let () = acc.push_back(fields.field());
+                acc
+            }
+            _ => panic!("can't instantiate variant #{algebraic_index} of `LinkedList`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(mut self) -> Parts<Store> {
+        let Some(caboose) = self.pop_back() else {
+            return Parts {
+                fields: Store::new(),
+                variant_index: Some(const { NonZero::new(1).unwrap() }),
+            };
+        };
+        let mut fields = Store::new();
+        let () = fields.push(caboose);
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(2).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: Multiset::new(),
+            },
+            Variant {
+                field_types: [TypeId::of::<Self>(), TypeId::of::<T>()]
+                    .into_iter()
+                    .collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<LinkedList<u8>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<LinkedList<u8>> = persist::replay();
+        let () = expected.extend(
+            [
+                &[][..],
+                &[],
+                &[],
+                &[],
+                &[156, 110],
+                &[39, 211],
+                &[4],
+                &[],
+                &[],
+                &[2],
+            ]
+            .map(|elements| elements.iter().copied().collect()),
+        );
+        let generated: Vec<LinkedList<u8>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<LinkedList<u8>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<LinkedList<u8>>();
+    }
+
+    #[test]
+    fn minimal_unsorted() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |list: &LinkedList<u8>| (!list.iter().is_sorted()).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((LinkedList::from([1, 0]), ())),
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Implementations for `core::sync::atomic::Ordering`.
+//!
+//! Variants are listed from weakest to strongest,
+//! so shrinking moves toward `Relaxed`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{num::NonZero, sync::atomic::Ordering},
+};
+
+impl_unit_enum!(Ordering {
+    Relaxed,
+    Release,
+    Acquire,
+    AcqRel,
+    SeqCst,
+});
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_enumeration, check_eta_expansion,
+            check_serialization, enumerate, persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<Ordering>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Ordering> = persist::replay();
+        let () = expected.extend([
+            Ordering::Relaxed,
+            Ordering::AcqRel,
+            Ordering::SeqCst,
+            Ordering::Acquire,
+            Ordering::AcqRel,
+            Ordering::Release,
+            Ordering::Relaxed,
+            Ordering::AcqRel,
+            Ordering::Release,
+            Ordering::Relaxed,
+        ]);
+        let generated: Vec<Ordering> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration() {
+        let all: Vec<Ordering> = enumerate().unwrap().collect();
+        assert_eq!(
+            all,
+            [
+                Ordering::Relaxed,
+                Ordering::Release,
+                Ordering::Acquire,
+                Ordering::AcqRel,
+                Ordering::SeqCst,
+            ],
+        );
+        let () = check_enumeration::<Ordering>();
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Ordering>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Ordering>();
+    }
+
+    #[test]
+    fn minimal_acquiring_ordering() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&ordering: &Ordering| {
+                    matches!(
+                        ordering,
+                        Ordering::Acquire | Ordering::AcqRel | Ordering::SeqCst
+                    )
+                    .then_some(())
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Ordering::Acquire, ())),
+        );
+    }
+}
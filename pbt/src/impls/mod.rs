@@ -4,15 +4,44 @@
 mod arcs;
 mod arrays;
 mod booleans;
+mod bounds;
 mod boxes;
+mod c_strings;
 mod chars;
+mod control_flows;
+mod cows;
+mod durations;
+#[macro_use] // for `impl_unit_enum!` in later modules
+mod enums;
+mod fp_categories;
 mod hash_collections;
 mod infallible;
+#[macro_use] // for `shrink!` and `small!` in later modules
 mod integers;
 #[cfg(feature = "serde_json")]
 mod json;
+mod layouts;
+mod linked_lists;
+mod memory_orderings;
+mod non_zeros;
+mod num_wrappers;
 mod options;
+mod orderings;
+mod overflowings;
 mod phantoms;
+mod polls;
+mod ranges;
+mod rcs;
+mod results;
+mod shutdowns;
 mod strings;
 mod tuples;
+#[cfg(feature = "uuid")]
+mod uuids;
 mod vectors;
+
+#[expect(
+    clippy::single_component_path_imports,
+    reason = "re-exports `shrink!` by path for modules declared before `impls`"
+)]
+pub(crate) use shrink;
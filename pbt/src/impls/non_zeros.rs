@@ -0,0 +1,158 @@
+//! Implementations for `core::num::NonZero` over unsigned integers.
+//!
+//! These behave like the underlying integer shifted up by one:
+//! generation favors small values and `MAX`, and shrinking moves toward `MIN`.
+
+use {
+    super::integers::uniform,
+    crate::{
+        Pbt,
+        coin_flips::CoinFlips,
+        fields::{Fields, Store},
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::{iter, num::NonZero},
+    wyrand::WyRand,
+};
+
+/// Implement `Pbt` for `NonZero<u_>`.
+macro_rules! impl_non_zero {
+    ($u:ty) => {
+        impl_non_zero!($u, |prng: &mut WyRand| {
+            #[allow(
+                clippy::allow_attributes,
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "intentional: keep only the low bits"
+            )]
+            (prng.rand() as $u)
+        });
+    };
+    ($u:ty, $uniform:expr) => {
+        impl Pbt for NonZero<$u> {
+            #[inline]
+            fn construct<F>(
+                Parts {
+                    mut fields,
+                    variant_index,
+                }: Parts<F>,
+            ) -> Self
+            where
+                F: Fields,
+            {
+                debug_assert_eq!(variant_index, None, "`NonZero` integers are literals");
+                fields.field()
+            }
+
+            #[inline]
+            fn deconstruct(self) -> Parts<Store> {
+                let mut fields = Store::new();
+                let () = fields.push(self);
+                Parts {
+                    fields,
+                    variant_index: None,
+                }
+            }
+
+            #[inline]
+            fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+                Variants::Literal {
+                    deserialize: |json| {
+                        let serde_json::Value::String(ref s) = *json else {
+                            return None;
+                        };
+                        s.parse().ok()
+                    },
+                    generators: vec![
+                        |_| Self::MAX,
+                        |prng| {
+                            let uniform: fn(&mut WyRand) -> $u = $uniform;
+                            Self::new(uniform(prng)).unwrap_or(Self::MIN)
+                        },
+                        |prng| {
+                            let small: fn(&mut WyRand) -> $u = small!($u);
+                            Self::MIN.saturating_add(small(prng))
+                        },
+                    ],
+                    serialize: |&i| i.get().to_string().into(),
+                    shrink: |i| {
+                        let offset = i.get().saturating_sub(1);
+                        Box::new(
+                            (shrink!($u))(offset).map(|shrunk| Self::MIN.saturating_add(shrunk)),
+                        )
+                    },
+                }
+            }
+        }
+    };
+}
+
+impl_non_zero!(u8);
+impl_non_zero!(u16);
+impl_non_zero!(u32);
+impl_non_zero!(u64);
+impl_non_zero!(usize, uniform);
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            reflection::register_globally, sample, shrink,
+        },
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn deterministic_u8() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<u8> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(NonZero::<u8>::get)
+            .collect();
+        let expected: Vec<u8> = vec![13, 255, 10, 1, 49, 4, 255, 1, 2, 4];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn u8_eta_expansion() {
+        let () = check_eta_expansion::<NonZero<u8>>();
+    }
+
+    #[test]
+    fn u8_serialization() {
+        let () = check_serialization::<NonZero<u8>>();
+    }
+
+    #[test]
+    fn usize_eta_expansion() {
+        let () = check_eta_expansion::<NonZero<usize>>();
+    }
+
+    #[test]
+    fn usize_serialization() {
+        let () = check_serialization::<NonZero<usize>>();
+    }
+
+    #[test]
+    fn option_niche_shrinks_to_none_then_min() {
+        let () = register_globally::<Option<NonZero<u8>>>();
+        let candidates: Vec<Option<NonZero<u8>>> =
+            shrink::candidates(NonZero::new(200)).take(2).collect();
+        assert_eq!(candidates, [None, Some(NonZero::<u8>::MIN)]);
+    }
+
+    #[test]
+    fn option_niche_covers_both_cases() {
+        let mut prng = WyRand::new(42);
+        let options: Vec<Option<NonZero<u8>>> = sample(DEFAULT_N_CASES, &mut prng);
+        assert!(options.contains(&None));
+        assert!(options.contains(&Some(NonZero::<u8>::MIN)));
+        assert!(options.contains(&Some(NonZero::<u8>::MAX)));
+    }
+}
@@ -0,0 +1,174 @@
+//! Implementations for `core::num::Wrapping` and `core::num::Saturating`.
+//!
+//! Unlike plain integers, these front-load arithmetic boundaries
+//! (`MAX`, `MAX - 1`, `0`, and `1`) so that wrapping and saturation are exercised early.
+
+use {
+    super::integers::uniform,
+    crate::{
+        Pbt,
+        coin_flips::CoinFlips,
+        fields::{Fields, Store},
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::{
+        iter,
+        num::{Saturating, Wrapping},
+    },
+    wyrand::WyRand,
+};
+
+/// Implement `Pbt` for an arithmetic wrapper around `u_`.
+macro_rules! impl_num_wrapper {
+    ($wrapper:ident, $u:ty) => {
+        impl_num_wrapper!($wrapper, $u, |prng: &mut WyRand| {
+            #[allow(
+                clippy::allow_attributes,
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "intentional: keep only the low bits"
+            )]
+            (prng.rand() as $u)
+        });
+    };
+    ($wrapper:ident, $u:ty, $uniform:expr) => {
+        impl Pbt for $wrapper<$u> {
+            #[inline]
+            fn construct<F>(
+                Parts {
+                    mut fields,
+                    variant_index,
+                }: Parts<F>,
+            ) -> Self
+            where
+                F: Fields,
+            {
+                debug_assert_eq!(variant_index, None, "arithmetic wrappers are literals");
+                fields.field()
+            }
+
+            #[inline]
+            fn deconstruct(self) -> Parts<Store> {
+                let mut fields = Store::new();
+                let () = fields.push(self);
+                Parts {
+                    fields,
+                    variant_index: None,
+                }
+            }
+
+            #[inline]
+            fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+                Variants::Literal {
+                    deserialize: |json| {
+                        let serde_json::Value::String(ref s) = *json else {
+                            return None;
+                        };
+                        s.parse().ok().map($wrapper)
+                    },
+                    generators: vec![
+                        |prng| {
+                            const BOUNDARIES: [$u; 4] = [<$u>::MAX, <$u>::MAX - 1, 0, 1];
+                            $wrapper(modulo::pick(prng, &BOUNDARIES))
+                        },
+                        |prng| {
+                            let uniform: fn(&mut WyRand) -> $u = $uniform;
+                            $wrapper(uniform(prng))
+                        },
+                        |prng| {
+                            let small: fn(&mut WyRand) -> $u = small!($u);
+                            $wrapper(small(prng))
+                        },
+                    ],
+                    serialize: |&$wrapper(i)| i.to_string().into(),
+                    shrink: |$wrapper(i)| Box::new((shrink!($u))(i).map($wrapper)),
+                }
+            }
+        }
+    };
+}
+
+impl_num_wrapper!(Wrapping, u8);
+impl_num_wrapper!(Wrapping, u16);
+impl_num_wrapper!(Wrapping, u32);
+impl_num_wrapper!(Wrapping, u64);
+impl_num_wrapper!(Wrapping, usize, uniform);
+impl_num_wrapper!(Saturating, u8);
+impl_num_wrapper!(Saturating, u16);
+impl_num_wrapper!(Saturating, u32);
+impl_num_wrapper!(Saturating, u64);
+impl_num_wrapper!(Saturating, usize, uniform);
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn deterministic_wrapping_u8() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<u8> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(|Wrapping(i)| i)
+            .collect();
+        let expected: Vec<u8> = vec![13, 254, 200, 230, 255, 255, 150, 1, 198, 229];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn boundaries_come_early() {
+        let mut prng = WyRand::new(42);
+        let early: Vec<Wrapping<u8>> = arbitrary(&mut prng).unwrap().take(100).collect();
+        for boundary in [255, 254, 0, 1] {
+            assert!(
+                early.contains(&Wrapping(boundary)),
+                "{boundary} should appear in the first 100 values",
+            );
+        }
+    }
+
+    #[test]
+    fn wrapping_u8_eta_expansion() {
+        let () = check_eta_expansion::<Wrapping<u8>>();
+    }
+
+    #[test]
+    fn wrapping_u8_serialization() {
+        let () = check_serialization::<Wrapping<u8>>();
+    }
+
+    #[test]
+    fn saturating_usize_eta_expansion() {
+        let () = check_eta_expansion::<Saturating<usize>>();
+    }
+
+    #[test]
+    fn saturating_usize_serialization() {
+        let () = check_serialization::<Saturating<usize>>();
+    }
+
+    #[test]
+    fn finds_wrapping_overflow() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&Wrapping(i): &Wrapping<u32>| (Wrapping(i) + Wrapping(1) < Wrapping(i))
+                    .then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Wrapping(u32::MAX), ())),
+        );
+    }
+}
@@ -74,7 +74,12 @@ mod tests {
     #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
 
     use {
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_enumeration,
+            check_eta_expansion, check_serialization, enumerate, reflection::register_globally,
+            sample, shrink, witness, witness_exhaustive,
+        },
+        core::convert::Infallible,
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -104,6 +109,41 @@ mod tests {
         assert_eq!(generated, expected);
     }
 
+    #[test]
+    fn degenerate_uninstantiable() {
+        let mut prng = WyRand::new(42);
+        let options: Vec<Option<Infallible>> = sample(DEFAULT_N_CASES, &mut prng);
+        assert_eq!(options.len(), DEFAULT_N_CASES);
+        assert!(options.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn bool_enumeration() {
+        assert_eq!(cardinality::<Option<bool>>(), Some(3));
+        assert_eq!(
+            enumerate::<Option<bool>>().map(Iterator::collect),
+            Some(vec![None, Some(false), Some(true)]),
+        );
+        let () = check_enumeration::<Option<bool>>();
+    }
+
+    #[test]
+    fn bool_exhaustive() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_exhaustive(
+                |&x: &Option<bool>| (x.unwrap_or_default() != (x == Some(true))).then_some(()),
+                3,
+                &mut prng,
+            ),
+            None,
+        );
+        assert_eq!(
+            witness_exhaustive(|&x: &Option<bool>| x?.then_some(()), 3, &mut prng),
+            Some((Some(true), ())),
+        );
+    }
+
     #[test]
     fn eta_expansion() {
         let () = check_eta_expansion::<Option<usize>>();
@@ -123,4 +163,49 @@ mod tests {
     fn serialization_deep() {
         let () = check_serialization::<Option<Option<usize>>>();
     }
+
+    #[test]
+    fn nested_shrink_order() {
+        let () = register_globally::<Option<Option<u8>>>();
+        let shrunk: Vec<Option<Option<u8>>> = shrink::candidates(Some(Some(5))).take(4).collect();
+        assert_eq!(shrunk, vec![None, Some(None), Some(Some(0)), Some(Some(3))]);
+    }
+
+    #[test]
+    fn minimal_nested() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |x: &Option<Option<u8>>| matches!(*x, Some(Some(n)) if n > 3).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Some(Some(4)), ())),
+        );
+    }
+
+    #[test]
+    fn nested_vec_shrink_order() {
+        let () = register_globally::<Option<Vec<Option<u8>>>>();
+        let first: Option<Option<Vec<Option<u8>>>> =
+            shrink::candidates(Some(vec![Some(5), None])).next();
+        assert_eq!(first, Some(None));
+    }
+
+    #[test]
+    fn minimal_nested_vec() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |x: &Option<Vec<Option<u8>>>| {
+                    let elements = x.as_ref()?;
+                    let n_some = elements.iter().filter(|element| element.is_some()).count();
+                    (n_some >= 2 && elements.iter().flatten().any(|&n| n >= 10)).then_some(())
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Some(vec![Some(0), Some(10)]), ())),
+        );
+    }
 }
@@ -0,0 +1,107 @@
+//! Implementations for `core::cmp::Ordering`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{cmp::Ordering, num::NonZero},
+};
+
+impl_unit_enum!(Ordering {
+    Less,
+    Equal,
+    Greater
+});
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            enumerate, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    /// Which side (`true` for left) a merge takes from at each step,
+    /// following a script of comparison outcomes instead of comparing elements.
+    /// Buggy: a tie right after taking from the right takes from the right again,
+    /// so the merge isn't stable.
+    fn buggy_merge_sides(script: &[Ordering]) -> Vec<bool> {
+        let mut took_left = true;
+        script
+            .iter()
+            .map(|&outcome| {
+                took_left = match outcome {
+                    Ordering::Less => true,
+                    Ordering::Equal => took_left,
+                    Ordering::Greater => false,
+                };
+                took_left
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Ordering> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Ordering> = vec![
+            Ordering::Equal,
+            Ordering::Equal,
+            Ordering::Less,
+            Ordering::Greater,
+            Ordering::Greater,
+            Ordering::Equal,
+            Ordering::Equal,
+            Ordering::Equal,
+            Ordering::Equal,
+            Ordering::Equal,
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn decision_script_breaks_stable_merge() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |script: &Vec<Ordering>| {
+                    let sides = buggy_merge_sides(script);
+                    script
+                        .iter()
+                        .zip(&sides)
+                        .any(|(&outcome, &left)| outcome == Ordering::Equal && !left)
+                        .then_some(sides)
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((vec![Ordering::Greater, Ordering::Equal], vec![false, false])),
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        let all: Vec<Ordering> = enumerate().unwrap().collect();
+        assert_eq!(all, [Ordering::Less, Ordering::Equal, Ordering::Greater]);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Ordering>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Ordering>();
+    }
+}
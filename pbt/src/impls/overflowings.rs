@@ -0,0 +1,314 @@
+//! Implementations for `pbt::overflow::Overflowing` and `pbt::overflow::Narrow`.
+
+use {
+    super::integers::uniform,
+    crate::{
+        Pbt,
+        coin_flips::CoinFlips,
+        fields::{Fields, Store},
+        modulo,
+        overflow::{Narrow, Overflowing},
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::iter,
+    wyrand::WyRand,
+};
+
+/// Implement `Pbt` for `Overflowing<u_>`.
+macro_rules! impl_overflowing {
+    ($u:ty) => {
+        impl_overflowing!($u, |prng: &mut WyRand| {
+            #[allow(
+                clippy::allow_attributes,
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "intentional: keep only the low bits"
+            )]
+            (prng.rand() as $u)
+        });
+    };
+    ($u:ty, $uniform:expr) => {
+        impl Pbt for Overflowing<$u> {
+            #[inline]
+            fn construct<F>(
+                Parts {
+                    mut fields,
+                    variant_index,
+                }: Parts<F>,
+            ) -> Self
+            where
+                F: Fields,
+            {
+                debug_assert_eq!(variant_index, None, "`Overflowing` is a literal");
+                fields.field()
+            }
+
+            #[inline]
+            fn deconstruct(self) -> Parts<Store> {
+                let mut fields = Store::new();
+                let () = fields.push(self);
+                Parts {
+                    fields,
+                    variant_index: None,
+                }
+            }
+
+            #[inline]
+            fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+                Variants::Literal {
+                    deserialize: |json| {
+                        let serde_json::Value::String(ref s) = *json else {
+                            return None;
+                        };
+                        s.parse().ok().map(Overflowing)
+                    },
+                    generators: vec![
+                        |prng| {
+                            const EDGE_CASES: [$u; 4] = [<$u>::MAX, <$u>::MAX - 1, 0, 1];
+                            Overflowing(modulo::pick(prng, &EDGE_CASES))
+                        },
+                        |prng| {
+                            let small: fn(&mut WyRand) -> $u = small!($u);
+                            Overflowing(<$u>::MAX.wrapping_sub(small(prng)))
+                        },
+                        |prng| {
+                            let small: fn(&mut WyRand) -> $u = small!($u);
+                            Overflowing(small(prng))
+                        },
+                        |prng| {
+                            let uniform: fn(&mut WyRand) -> $u = $uniform;
+                            Overflowing(uniform(prng))
+                        },
+                    ],
+                    serialize: |&Overflowing(i)| i.to_string().into(),
+                    shrink: |Overflowing(i)| Box::new((shrink!($u))(i).map(Overflowing)),
+                }
+            }
+        }
+    };
+}
+
+/// Implement `Pbt` for `Narrow<u_, u_>`, wide first.
+macro_rules! impl_narrow {
+    ($wide:ty, $target:ty) => {
+        impl Pbt for Narrow<$wide, $target> {
+            #[inline]
+            fn construct<F>(
+                Parts {
+                    mut fields,
+                    variant_index,
+                }: Parts<F>,
+            ) -> Self
+            where
+                F: Fields,
+            {
+                debug_assert_eq!(variant_index, None, "`Narrow` is a literal");
+                fields.field()
+            }
+
+            #[inline]
+            fn deconstruct(self) -> Parts<Store> {
+                let mut fields = Store::new();
+                let () = fields.push(self);
+                Parts {
+                    fields,
+                    variant_index: None,
+                }
+            }
+
+            #[inline]
+            fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+                /// The largest value that fits in the target type.
+                #[allow(
+                    clippy::allow_attributes,
+                    clippy::as_conversions,
+                    reason = "lossless: the target type is narrower"
+                )]
+                const BOUNDARY: $wide = <$target>::MAX as $wide;
+                Variants::Literal {
+                    deserialize: |json| {
+                        let serde_json::Value::String(ref s) = *json else {
+                            return None;
+                        };
+                        s.parse().ok().map(Narrow::new)
+                    },
+                    generators: vec![
+                        |prng| {
+                            const EDGE_CASES: [$wide; 2] = [BOUNDARY, BOUNDARY + 1];
+                            Narrow::new(modulo::pick(prng, &EDGE_CASES))
+                        },
+                        |prng| {
+                            let small: fn(&mut WyRand) -> $wide = small!($wide);
+                            Narrow::new(BOUNDARY.saturating_sub(small(prng)))
+                        },
+                        |prng| {
+                            let small: fn(&mut WyRand) -> $wide = small!($wide);
+                            Narrow::new((BOUNDARY + 1).saturating_add(small(prng)))
+                        },
+                        |prng| {
+                            #[allow(
+                                clippy::allow_attributes,
+                                clippy::as_conversions,
+                                clippy::cast_possible_truncation,
+                                reason = "intentional: keep only the low bits"
+                            )]
+                            Narrow::new(prng.rand() as $wide)
+                        },
+                    ],
+                    serialize: |&narrow| narrow.get().to_string().into(),
+                    shrink: |narrow| Box::new((shrink!($wide))(narrow.get()).map(Narrow::new)),
+                }
+            }
+        }
+    };
+}
+
+impl_narrow!(u16, u8);
+impl_narrow!(u32, u8);
+impl_narrow!(u32, u16);
+impl_narrow!(u64, u8);
+impl_narrow!(u64, u16);
+impl_narrow!(u64, u32);
+
+impl_overflowing!(u8);
+impl_overflowing!(u16);
+impl_overflowing!(u32);
+impl_overflowing!(u64);
+impl_overflowing!(usize, uniform);
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES,
+            arbitrary::{arbitrary, generate},
+            check_eta_expansion, check_serialization, witness,
+        },
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<u8> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(|Overflowing(i)| i)
+            .collect();
+        let expected: Vec<u8> = vec![0, 1, 249, 255, 1, 255, 3, 132, 3, 254];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Overflowing<u8>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Overflowing<u8>>();
+    }
+
+    #[test]
+    fn narrow_deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<u16> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(Narrow::<u16, u8>::get)
+            .collect();
+        let expected: Vec<u16> = vec![256, 257, 249, 255, 257, 255, 259, 55_940, 259, 256];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn narrow_eta_expansion() {
+        let () = check_eta_expansion::<Narrow<u32, u8>>();
+    }
+
+    #[test]
+    fn narrow_serialization() {
+        let () = check_serialization::<Narrow<u32, u8>>();
+    }
+
+    #[test]
+    fn narrow_mostly_near_boundary() {
+        let mut prng = WyRand::new(42);
+        let near_boundary = arbitrary::<Narrow<u64, u32>>(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .filter(|narrow| narrow.get().abs_diff(u64::from(u32::MAX)) < 1 << 16_u32)
+            .count();
+        assert!(
+            near_boundary > DEFAULT_N_CASES >> 1_u32,
+            "only {near_boundary} of {DEFAULT_N_CASES} values were near `u32::MAX`",
+        );
+    }
+
+    #[test]
+    fn minimal_u32_not_fitting_in_u8() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |narrow: &Narrow<u32, u8>| u8::try_from(narrow.get()).err(),
+                DEFAULT_N_CASES,
+                &mut prng,
+            )
+            .map(|(narrow, _)| narrow.get()),
+            Some(256),
+        );
+    }
+
+    #[test]
+    fn usize_eta_expansion() {
+        let () = check_eta_expansion::<Overflowing<usize>>();
+    }
+
+    #[test]
+    fn usize_serialization() {
+        let () = check_serialization::<Overflowing<usize>>();
+    }
+
+    #[test]
+    fn mostly_near_extremes() {
+        let mut prng = WyRand::new(42);
+        let near_extremes = arbitrary::<Overflowing<u32>>(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .filter(|&Overflowing(i)| i.leading_zeros() >= 16 || i.leading_ones() >= 16)
+            .count();
+        assert!(
+            near_extremes > DEFAULT_N_CASES >> 1_u32,
+            "only {near_extremes} of {DEFAULT_N_CASES} values were near `0` or `u32::MAX`",
+        );
+    }
+
+    #[test]
+    fn overflowing_sum() {
+        let overflows =
+            |&(Overflowing(lhs), Overflowing(rhs)): &(Overflowing<u8>, Overflowing<u8>)| {
+                lhs.checked_add(rhs).is_none()
+            };
+        let first = generate(&mut WyRand::new(42))
+            .unwrap()
+            .position(|pair| overflows(&pair));
+        assert!(
+            matches!(first, Some(i) if i < 10),
+            "first overflow at {first:?}"
+        );
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |pair| overflows(pair).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            // Shrinking either side alone stops the overflow once the sum is exactly 256:
+            Some(((Overflowing(90), Overflowing(166)), ())),
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Implementations for `Poll<_>`.
+//!
+//! `Pending` comes first, so shrinking tries it before any `Ready` value.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero, task::Poll},
+};
+
+impl<T> Pbt for Poll<T>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Poll` is not a literal").get();
+        match algebraic_index {
+            1 => Self::Pending,
+            2 => Self::Ready(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Poll`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let variant_index = match self {
+            Self::Pending => const { NonZero::new(1).unwrap() },
+            Self::Ready(t) => {
+                let () = fields.push(t);
+                const { NonZero::new(2).unwrap() }
+            }
+        };
+        Parts {
+            fields,
+            variant_index: Some(variant_index),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: Multiset::new(),
+            },
+            Variant {
+                field_types: iter::once(TypeId::of::<T>()).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_enumeration,
+            check_eta_expansion, check_serialization, enumerate, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Poll<usize>> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Poll<usize>> = vec![
+            Poll::Ready(17_850_812_975_400_668_360),
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+            Poll::Pending,
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration() {
+        assert_eq!(cardinality::<Poll<bool>>(), Some(3));
+        assert_eq!(
+            enumerate::<Poll<bool>>().map(Iterator::collect),
+            Some(vec![Poll::Pending, Poll::Ready(false), Poll::Ready(true)]),
+        );
+        let () = check_enumeration::<Poll<bool>>();
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Poll<usize>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Poll<usize>>();
+    }
+
+    #[test]
+    fn minimal_ready() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |poll: &Poll<u8>| match *poll {
+                    Poll::Pending => None,
+                    Poll::Ready(n) => n.checked_sub(42),
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Poll::Ready(42), 0)),
+        );
+    }
+}
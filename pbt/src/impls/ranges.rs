@@ -0,0 +1,225 @@
+//! Implementations for the unbounded and half-bounded ranges
+//! `RangeFull`, `RangeFrom<_>`, and `RangeTo<_>`.
+//!
+//! Each is a single-variant algebraic type over its endpoints (if any),
+//! so e.g. `RangeTo<u8>` shrinks exactly as its `end` would.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{
+        any::TypeId,
+        iter,
+        num::NonZero,
+        ops::{RangeFrom, RangeFull, RangeTo},
+    },
+};
+
+impl Pbt for RangeFull {
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(Parts { variant_index, .. }: Parts<F>) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`RangeFull` is not a literal").get();
+        match algebraic_index {
+            1 => Self,
+            _ => panic!("can't instantiate variant #{algebraic_index} of `RangeFull`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        Parts {
+            fields: Store::new(),
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Algebraic(vec![Variant {
+            field_types: Multiset::new(),
+        }])
+    }
+}
+
+impl<T> Pbt for RangeFrom<T>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`RangeFrom` is not a literal").get();
+        match algebraic_index {
+            1 => fields.field()..,
+            _ => panic!("can't instantiate variant #{algebraic_index} of `RangeFrom`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.start);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<T>()).collect(),
+        }])
+    }
+}
+
+impl<T> Pbt for RangeTo<T>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`RangeTo` is not a literal").get();
+        match algebraic_index {
+            1 => ..fields.field(),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `RangeTo`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.end);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<T>()).collect(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_enumeration,
+            check_eta_expansion, check_serialization, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<RangeTo<usize>> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<RangeTo<usize>> = vec![
+            ..0,
+            ..7_804_948_724_862_110_416,
+            ..17_108_568_891_541_767_080,
+            ..14_756_591_828_928_955_088,
+            ..1,
+            ..1,
+            ..10,
+            ..19,
+            ..13,
+            ..0,
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn full_has_one_value() {
+        assert_eq!(cardinality::<RangeFull>(), Some(1));
+        let () = check_enumeration::<RangeFull>();
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<RangeFull>();
+        let () = check_eta_expansion::<RangeFrom<usize>>();
+        let () = check_eta_expansion::<RangeTo<usize>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<RangeFull>();
+        let () = check_serialization::<RangeFrom<usize>>();
+        let () = check_serialization::<RangeTo<usize>>();
+    }
+
+    #[test]
+    fn minimal_range_from() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |range: &RangeFrom<u8>| (!range.contains(&100)).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((101.., ())),
+        );
+    }
+
+    #[test]
+    fn minimal_range_to() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |range: &RangeTo<u8>| range.contains(&100).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((..101, ())),
+        );
+    }
+}
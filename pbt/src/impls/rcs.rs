@@ -0,0 +1,127 @@
+//! Implementations for `Rc<_>`.
+//!
+//! Shared aliasing is not modeled:
+//! every generated `Rc` is the unique owner of its contents.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    alloc::rc::Rc,
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+impl<T> Pbt for Rc<T>
+where
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Rc` is not a literal").get();
+        match algebraic_index {
+            1 => Rc::new(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Rc`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push::<T>(Rc::unwrap_or_clone(self));
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<T>()).collect(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Rc<usize>> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Rc<usize>> = vec![
+            Rc::new(0),
+            Rc::new(7_804_948_724_862_110_416),
+            Rc::new(17_108_568_891_541_767_080),
+            Rc::new(14_756_591_828_928_955_088),
+            Rc::new(1),
+            Rc::new(1),
+            Rc::new(10),
+            Rc::new(19),
+            Rc::new(13),
+            Rc::new(0),
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Rc<usize>>();
+    }
+
+    #[test]
+    fn eta_expansion_deep() {
+        let () = check_eta_expansion::<Rc<Rc<usize>>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Rc<usize>>();
+    }
+
+    #[test]
+    fn serialization_deep() {
+        let () = check_serialization::<Rc<Rc<usize>>>();
+    }
+
+    #[test]
+    fn witness_at_least_1000() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |n: &Rc<u32>| n.checked_sub(1000),
+                DEFAULT_N_CASES,
+                &mut prng
+            ),
+            Some((Rc::new(1000), 0)),
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Implementations for `Result<_, _>`.
+//!
+//! `Ok` comes first, so e.g. `Result<(), E>` shrinks to `Ok(())`
+//! whenever that still satisfies the property, and otherwise to the simplest `Err`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+impl<T, E> Pbt for Result<T, E>
+where
+    E: Pbt,
+    T: Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        clippy::panic_in_result_fn,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Result` is not a literal").get();
+        match algebraic_index {
+            1 => Ok(fields.field()),
+            2 => Err(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Result`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let variant_index = match self {
+            Ok(t) => {
+                let () = fields.push(t);
+                const { NonZero::new(1).unwrap() }
+            }
+            Err(e) => {
+                let () = fields.push(e);
+                const { NonZero::new(2).unwrap() }
+            }
+        };
+        Parts {
+            fields,
+            variant_index: Some(variant_index),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        let () = registration.register::<E>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: iter::once(TypeId::of::<T>()).collect(),
+            },
+            Variant {
+                field_types: iter::once(TypeId::of::<E>()).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_enumeration,
+            check_eta_expansion, check_serialization, enumerate, witness,
+        },
+        core::convert::Infallible,
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Result<usize, usize>> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Result<usize, usize>> = vec![
+            Err(17_850_812_975_400_668_360),
+            Ok(13_639_797_723_846_260_844),
+            Ok(0),
+            Ok(14_559_301_688_031_195_406),
+            Ok(0),
+            Ok(3),
+            Err(1_501_726_134_688_862_675),
+            Err(3),
+            Ok(0),
+            Err(5_252_106_543_385_571_987),
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn cardinalities() {
+        assert_eq!(cardinality::<Result<(), bool>>(), Some(3));
+        assert_eq!(cardinality::<Result<(), Infallible>>(), Some(1));
+        assert_eq!(cardinality::<Result<(), u8>>(), None);
+    }
+
+    #[test]
+    fn enumeration() {
+        let () = check_enumeration::<Result<(), bool>>();
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Result<usize, bool>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Result<usize, bool>>();
+    }
+
+    #[test]
+    fn pipeline_enumeration() {
+        assert_eq!(
+            enumerate::<(Result<(), ()>, Result<(), ()>)>().map(Iterator::collect),
+            Some(vec![
+                (Ok(()), Ok(())),
+                (Ok(()), Err(())),
+                (Err(()), Ok(())),
+                (Err(()), Err(()))
+            ]),
+        );
+    }
+
+    #[test]
+    fn pipeline_generation_covers_every_pattern() {
+        let mut prng = WyRand::new(42);
+        let patterns: Vec<[bool; 3]> =
+            arbitrary::<(Result<u8, bool>, Result<u8, bool>, Result<u8, bool>)>(&mut prng)
+                .unwrap()
+                .take(100)
+                .map(|(a, b, c)| [a.is_ok(), b.is_ok(), c.is_ok()])
+                .collect();
+        for a in [false, true] {
+            for b in [false, true] {
+                for c in [false, true] {
+                    assert!(
+                        patterns.contains(&[a, b, c]),
+                        "no `is_ok` pattern {:?} in the first 100 values",
+                        [a, b, c],
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shrinks_to_ok_first() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |result: &Result<(), u8>| result.is_ok().then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Ok(()), ())),
+        );
+    }
+
+    #[test]
+    fn minimal_error() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |result: &Result<(), u8>| result.err()?.checked_sub(42),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Err(42), 0)),
+        );
+    }
+}
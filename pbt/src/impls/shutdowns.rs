@@ -0,0 +1,75 @@
+//! Implementations for `std::net::Shutdown`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        multiset::Multiset,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::num::NonZero,
+    std::net::Shutdown,
+};
+
+impl_unit_enum!(Shutdown { Read, Write, Both });
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_enumeration, check_eta_expansion,
+            check_serialization, enumerate, sample,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Shutdown> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Shutdown> = vec![
+            Shutdown::Write,
+            Shutdown::Write,
+            Shutdown::Read,
+            Shutdown::Both,
+            Shutdown::Both,
+            Shutdown::Write,
+            Shutdown::Write,
+            Shutdown::Write,
+            Shutdown::Write,
+            Shutdown::Write,
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration() {
+        let all: Vec<Shutdown> = enumerate().unwrap().collect();
+        assert_eq!(all, [Shutdown::Read, Shutdown::Write, Shutdown::Both]);
+        let () = check_enumeration::<Shutdown>();
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Shutdown>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Shutdown>();
+    }
+
+    #[test]
+    fn generation_covers_every_variant() {
+        let mut prng = WyRand::new(42);
+        let samples: Vec<Shutdown> = sample(DEFAULT_N_CASES, &mut prng);
+        for variant in [Shutdown::Read, Shutdown::Write, Shutdown::Both] {
+            assert!(samples.contains(&variant), "`{variant:?}` never generated");
+        }
+    }
+}
@@ -1,4 +1,7 @@
 //! Implementations for `(_, _)`.
+//!
+//! Tuples shrink like any other constructor, field by field from the left,
+//! so e.g. the minimal `(u8, u8)` summing to more than 10 is `(0, 11)`.
 
 use {
     crate::{
@@ -91,7 +94,10 @@ mod tests {
     #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
 
     use {
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -200,4 +206,17 @@ mod tests {
     fn serialization_triple() {
         let () = check_serialization::<(usize, bool, bool)>();
     }
+
+    #[test]
+    fn minimal_pair_sum() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&(a, b): &(u8, u8)| (u16::from(a) + u16::from(b) > 10).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some(((0, 11), ())),
+        );
+    }
 }
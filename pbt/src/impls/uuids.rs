@@ -0,0 +1,165 @@
+//! Implementations for `uuid::Uuid`.
+//!
+//! Every generated `Uuid` is a valid version-4 (random) UUID:
+//! the version and variant bits are always set,
+//! and only the remaining 122 random bits are generated or shrunk.
+//! For raw bytes (e.g. the nil or max UUID), generate `bits::Bytes<16>`
+//! and convert with `Uuid::from_bytes`.
+
+use {
+    crate::{
+        Pbt,
+        bits::{Bytes, shrink_bytes, uniform_bytes},
+        fields::{Fields, Store},
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::num::NonZero,
+    uuid::{Builder, Uuid},
+};
+
+impl Pbt for Uuid {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`uuid::Uuid` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                Uuid::parse_str(s).ok()
+            },
+            generators: vec![|prng| v4(uniform_bytes(prng)), |prng| {
+                if modulo::below(prng, const { NonZero::new(2).unwrap() }) == 0 {
+                    v4(Bytes([0; 16]))
+                } else {
+                    v4(Bytes([0xFF; 16]))
+                }
+            }],
+            serialize: |uuid| uuid.hyphenated().to_string().into(),
+            shrink: |uuid| Box::new(shrink_bytes(random_bits(uuid)).map(v4)),
+        }
+    }
+}
+
+/// The bytes of a UUID with its version and variant bits cleared.
+#[inline]
+fn random_bits(uuid: Uuid) -> Bytes<16> {
+    let mut bytes = uuid.into_bytes();
+    if let Some(version) = bytes.get_mut(6) {
+        *version &= 0x0F;
+    }
+    if let Some(variant) = bytes.get_mut(8) {
+        *variant &= 0x3F;
+    }
+    Bytes(bytes)
+}
+
+/// A version-4 UUID, overwriting the version and variant bits.
+///
+/// All zeros and all ones become the smallest and largest version-4 UUIDs,
+/// i.e. the closest to the nil and max UUIDs.
+#[inline]
+fn v4(Bytes(bytes): Bytes<16>) -> Uuid {
+    Builder::from_random_bytes(bytes).into_uuid()
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            reflection::register_globally, shrink, witness,
+        },
+        pretty_assertions::assert_eq,
+        uuid::Version,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<Uuid> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<Uuid> = [
+            "00000000-0000-4000-8000-000000000000",
+            "00000000-0000-4000-8000-000000000000",
+            "00000000-0000-4000-8000-000000000000",
+            "e6c8b1cb-57e5-4d91-904a-8b4b83bf506c",
+            "6cb8095a-c34b-4abd-a843-a503c3d86ded",
+            "d148d4bf-4771-4cff-84c2-ae3f5a2098d9",
+            "a7666568-f6c9-46f6-9099-9b59f492ee0f",
+            "0e31d82c-bf07-4dca-9450-722bae60f32f",
+            "ffffffff-ffff-4fff-bfff-ffffffffffff",
+            "a1f204ef-e244-4231-a699-018657099e21",
+        ]
+        .into_iter()
+        .map(|s| Uuid::parse_str(s).unwrap())
+        .collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Uuid>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Uuid>();
+    }
+
+    #[test]
+    fn shrinks_toward_nil() {
+        let () = register_globally::<Uuid>();
+        let max = v4(Bytes([0xFF; 16]));
+        assert_eq!(
+            max,
+            Uuid::parse_str("ffffffff-ffff-4fff-bfff-ffffffffffff").unwrap()
+        );
+        assert_eq!(
+            shrink::candidates(max).next(),
+            Some(Uuid::parse_str("00000000-0000-4000-8000-000000000000").unwrap()),
+        );
+    }
+
+    #[test]
+    fn always_version_4() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |uuid: &Uuid| (uuid.get_version() != Some(Version::Random))
+                    .then_some(uuid.get_version_num()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+}
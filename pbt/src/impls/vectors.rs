@@ -80,7 +80,9 @@ mod tests {
     #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
 
     use {
-        crate::{arbitrary::arbitrary, check_eta_expansion, check_serialization},
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization, sample,
+        },
         pretty_assertions::assert_eq,
         wyrand::WyRand,
     };
@@ -104,6 +106,15 @@ mod tests {
         assert_eq!(generated, expected);
     }
 
+    #[test]
+    fn degenerate_units() {
+        let mut prng = WyRand::new(42);
+        let vecs: Vec<Vec<()>> = sample(DEFAULT_N_CASES, &mut prng);
+        assert!(vecs.iter().any(|v| v.len() > 1));
+        let () = check_eta_expansion::<Vec<()>>();
+        let () = check_serialization::<Vec<()>>();
+    }
+
     #[test]
     fn eta_expansion() {
         let () = check_eta_expansion::<Vec<usize>>();
@@ -4,20 +4,37 @@
 extern crate alloc;
 
 mod arbitrary;
+pub mod bits;
+pub mod bounded;
 mod coin_flips;
+mod enumerate;
+pub mod faults;
 pub mod fields;
+pub mod fuzz;
+pub mod generator;
 pub mod hash;
+#[macro_use] // for `shrink!` in later modules
 mod impls;
 mod instantiability;
+mod modulo;
 pub mod multiset;
+pub mod overflow;
+pub mod pairs;
 pub mod panic;
 pub mod persist;
+pub mod process;
+pub mod range;
 pub mod reflection;
 pub mod registration;
 mod scc;
 mod shrink;
 mod size;
+pub mod sort;
+pub mod sorted;
 mod swarm;
+pub mod tables;
+pub mod text;
+pub mod time;
 mod unavoidability;
 mod union_find;
 
@@ -26,6 +43,11 @@ pub use {
     wyrand::WyRand,
 };
 
+use {
+    core::{error, fmt, str::FromStr},
+    std::env,
+};
+
 /// The default number of cases to check if no alternate is specified.
 #[cfg(not(miri))]
 pub const DEFAULT_N_CASES: usize = 10_000;
@@ -34,6 +56,100 @@ pub const DEFAULT_N_CASES: usize = 10_000;
 #[cfg(miri)]
 pub const DEFAULT_N_CASES: usize = 10;
 
+/// The most values `check_enumeration` will check,
+/// since checking them is quadratic in their number.
+pub const MAX_CHECKED_ENUMERATION: usize = 1 << 12_u32;
+
+/// The result of a search whose shrinking may have been cut short.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "callers should be able to match every outcome"
+)]
+pub enum Outcome<T, Proof> {
+    /// A witness that could not be shrunk any further.
+    Minimal(T, Proof),
+    /// No witness was found.
+    NotFound,
+    /// A witness whose shrinking ran out of budget,
+    /// so a smaller witness may exist.
+    Partial(T, Proof),
+}
+
+/// The result of checking a property with a precondition on one input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "callers should be able to match every result"
+)]
+pub enum TestResult<Proof> {
+    /// The input didn't meet the precondition, so it says nothing about the property.
+    Discard,
+    /// The input met the precondition but not the property.
+    Fail(Proof),
+    /// The input met both the precondition and the property.
+    Pass,
+}
+
+impl<Proof> TestResult<Proof> {
+    /// The proof of failure, if this is a failure.
+    #[inline]
+    #[must_use]
+    pub fn failure(self) -> Option<Proof> {
+        match self {
+            Self::Fail(proof) => Some(proof),
+            Self::Discard | Self::Pass => None,
+        }
+    }
+}
+
+/// Too many inputs failed a property's precondition
+/// to check the property on as many inputs as requested.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct TooManyDiscards {
+    /// How many inputs were discarded (one more than the cap).
+    pub discarded: usize,
+    /// How many inputs passed before giving up.
+    pub passed: usize,
+}
+
+impl fmt::Display for TooManyDiscards {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "gave up after discarding {} inputs (only {} passed); is the precondition too strict?",
+            self.discarded, self.passed,
+        )
+    }
+}
+
+impl error::Error for TooManyDiscards {}
+
+/// An environment that's torn down when dropped, even during a panic.
+struct Environment<'teardown, Env, Teardown>
+where
+    Teardown: Fn(Env),
+{
+    /// The environment itself, taken only when dropped.
+    env: Option<Env>,
+    /// What to do with the environment when it's no longer needed.
+    teardown: &'teardown Teardown,
+}
+
+impl<Env, Teardown> Drop for Environment<'_, Env, Teardown>
+where
+    Teardown: Fn(Env),
+{
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(env) = self.env.take() {
+            let () = (self.teardown)(env);
+        }
+    }
+}
+
 /// The main property-based testing trait.
 #[expect(
     clippy::absolute_paths,
@@ -73,6 +189,53 @@ pub trait Pbt: 'static + Clone + core::fmt::Debug {
     fn register(registration: &mut registration::Registration<'_>) -> reflection::Variants<Self>;
 }
 
+/// Check that a finite type's enumeration is consistent with its other behavior:
+/// it has exactly `cardinality` distinct values, and every generated value
+/// and every shrinking candidate of every value appears in it.
+///
+/// Does nothing if `T` is not finite (see `enumerate`)
+/// or has more than `MAX_CHECKED_ENUMERATION` values.
+///
+/// # Panics
+///
+/// If any of the above fails, e.g. if a constructor builds a value
+/// that the type's declared variants could never produce.
+#[inline]
+pub fn check_enumeration<T>()
+where
+    T: PartialEq + Pbt,
+{
+    let Some(all) = cardinality::<T>()
+        .filter(|&n| n <= MAX_CHECKED_ENUMERATION)
+        .and_then(|_| enumerate::all::<T>())
+    else {
+        return;
+    };
+    pretty_assertions::assert_eq!(Some(all.len()), cardinality::<T>());
+    for (i, t) in all.iter().enumerate() {
+        assert!(
+            !all.iter().skip(i.saturating_add(1)).any(|u| u == t),
+            "`{t:?}` appears more than once in {all:?}",
+        );
+        for candidate in shrink::candidates(t.clone()) {
+            assert!(
+                all.contains(&candidate),
+                "shrinking `{t:?}` yielded `{candidate:?}`, which is not in {all:?}",
+            );
+        }
+    }
+    let mut prng = wyrand::WyRand::new(getrandom());
+    let Ok(arbitrary) = arbitrary::arbitrary::<T>(&mut prng) else {
+        return;
+    };
+    for t in arbitrary.take(DEFAULT_N_CASES >> 2) {
+        assert!(
+            all.contains(&t),
+            "generated `{t:?}`, which is not in {all:?}"
+        );
+    }
+}
+
 /// Check that deconstructing and then immediately reconstructing a value is a no-op.
 #[inline]
 pub fn check_eta_expansion<T>()
@@ -115,6 +278,113 @@ where
     }
 }
 
+/// Check that shrinking makes progress: accepting every first candidate
+/// (as if the property always held) must reach a value with no candidates
+/// within `max_steps` steps, never revisiting a value along the way.
+///
+/// # Panics
+///
+/// If shrinking revisits a value or takes more than `max_steps` steps.
+#[inline]
+#[expect(clippy::panic, reason = "failing checks ought to panic")]
+pub fn check_shrink_termination<T>(start: &T, max_steps: usize)
+where
+    T: PartialEq + Pbt,
+{
+    let () = reflection::register_globally::<T>();
+    let mut visited = vec![];
+    let mut current = start.clone();
+    for _ in 0..max_steps {
+        let Some(next) = shrink::candidates(current.clone()).next() else {
+            return;
+        };
+        assert!(
+            next != current && !visited.contains(&next),
+            "shrinking `{current:?}` cycled back to `{next:?}`",
+        );
+        let () = visited.push(current);
+        current = next;
+    }
+    panic!("shrinking `{start:?}` took more than {max_steps} steps (reached `{current:?}`)")
+}
+
+/// Parse an optional environment-variable override, ignoring empty values.
+///
+/// # Panics
+///
+/// If the value is present but unparseable.
+#[inline]
+#[expect(clippy::panic, reason = "misconfiguration should fail loudly")]
+fn parse_override<T>(var: &str, value: Option<String>) -> Option<T>
+where
+    T: FromStr,
+{
+    let nonempty = value.filter(|s| !s.is_empty())?;
+    let Ok(parsed) = nonempty.parse() else {
+        panic!("`{var}` should be a number, but it's `{nonempty}`");
+    };
+    Some(parsed)
+}
+
+/// The number of cases `#[pbt]` checks when none is specified.
+///
+/// Precedence: an explicit `#[pbt(n)]`, then the `PBT_ITERATIONS` environment variable,
+/// then `DEFAULT_N_CASES`.
+///
+/// # Panics
+///
+/// If `PBT_ITERATIONS` is set but not a valid `usize`.
+#[inline]
+#[must_use]
+pub fn n_cases() -> usize {
+    parse_override("PBT_ITERATIONS", env::var("PBT_ITERATIONS").ok()).unwrap_or(DEFAULT_N_CASES)
+}
+
+/// The seed with which `#[pbt]` starts its pseudorandom number generator.
+///
+/// Set the `PBT_SEED` environment variable to reproduce a run;
+/// otherwise, this is freshly random (via `getrandom`).
+///
+/// # Panics
+///
+/// If `PBT_SEED` is set but not a valid `u64`,
+/// or if `PBT_SEED` is unset and the `getrandom` crate panics.
+#[inline]
+#[must_use]
+pub fn seed() -> u64 {
+    parse_override("PBT_SEED", env::var("PBT_SEED").ok()).unwrap_or_else(getrandom)
+}
+
+/// The number of values of a finite type, computed structurally without enumerating them,
+/// e.g. `Some(4)` for `(bool, bool)`.
+///
+/// Returns `None` under the same conditions as `enumerate`.
+#[inline]
+#[must_use]
+pub fn cardinality<T>() -> Option<usize>
+where
+    T: Pbt,
+{
+    enumerate::count::<T>(&mut vec![])
+}
+
+/// Iterate over every value of a finite type, e.g. `bool` or `(bool, Option<bool>)`.
+///
+/// Variants appear in source order, and later fields vary faster than earlier ones.
+/// Values are constructed one at a time, so e.g. `.take(3)` is cheap
+/// even for types with trillions of values.
+/// Returns `None` if `T` has infinitely many values
+/// (including all literal types, e.g. `u8`)
+/// or more than fit in a `usize`.
+#[inline]
+#[must_use]
+pub fn enumerate<T>() -> Option<Box<dyn Iterator<Item = T>>>
+where
+    T: Pbt,
+{
+    Some(Box::new(enumerate::values::<T>()?))
+}
+
 /// Get a(n expensive) random `u64` from the OS via the `getrandom` crate.
 ///
 /// # Panics
@@ -130,10 +400,28 @@ pub fn getrandom() -> u64 {
     getrandom::u64().expect("INTERNAL ERROR (`pbt`): `getrandom` failed")
 }
 
+/// Generate `n` fresh arbitrary values of type `T`,
+/// setting up generation once rather than once per value.
+///
+/// Persisted witnesses are not replayed.
+/// If `T` is uninstantiable, this returns an empty `Vec`.
+#[inline]
+#[must_use]
+pub fn sample<T>(n: usize, prng: &mut wyrand::WyRand) -> Vec<T>
+where
+    T: Pbt,
+{
+    arbitrary::generate(prng).map_or_else(|_| vec![], |fresh| fresh.take(n).collect())
+}
+
 /// Search for the smallest witness of an arbitrary property, if one exists.
 ///
 /// If this fails, this does not mean that the property never holds;
 /// instead, it simply means we didn't find a property in `cases` cases.
+///
+/// `cases` and `prng` are used exactly as given:
+/// only `#[pbt]` tests consult `PBT_SEED` and `PBT_ITERATIONS` (via `seed` and `n_cases`).
+/// To do the same here, pass `n_cases()` and `WyRand::new(seed())`.
 #[inline]
 pub fn witness<T, Property, Proof>(
     property: Property,
@@ -153,9 +441,373 @@ where
     None
 }
 
+/// Search for the smallest witness of an arbitrary property, if one exists,
+/// checking *every* value of `T` if there are at most `cases` of them.
+///
+/// For types with more than `cases` values (or infinitely many),
+/// this is exactly `witness`.
+/// Otherwise, `None` means the property holds for every value of `T`.
+#[inline]
+pub fn witness_exhaustive<T, Property, Proof>(
+    property: Property,
+    cases: usize,
+    prng: &mut wyrand::WyRand,
+) -> Option<(T, Proof)>
+where
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let Some(all) = cardinality::<T>()
+        .filter(|&n| n <= cases)
+        .and_then(|_| enumerate::values::<T>())
+    else {
+        return witness(property, cases, prng);
+    };
+    for t in all {
+        if let Some(proof) = property(&t) {
+            return Some(shrink::to_minimal_witness(&property, t, proof));
+        }
+    }
+    None
+}
+
+/// Search for the smallest witness of a property with a precondition, if one exists.
+///
+/// Discarded inputs don't count toward `cases`,
+/// but discarding more than `max_discards` inputs gives up with `TooManyDiscards`.
+/// While shrinking, discarded candidates are treated like passing ones.
+///
+/// # Errors
+///
+/// If more than `max_discards` inputs are discarded before `cases` inputs pass.
+#[inline]
+pub fn witness_conditional<T, Property, Proof>(
+    property: Property,
+    cases: usize,
+    max_discards: usize,
+    prng: &mut wyrand::WyRand,
+) -> Result<Option<(T, Proof)>, TooManyDiscards>
+where
+    Property: Fn(&T) -> TestResult<Proof>,
+    T: Pbt,
+{
+    let Ok(mut arbitrary) = arbitrary::arbitrary::<T>(prng) else {
+        return Ok(None);
+    };
+    let mut passed = 0_usize;
+    let mut discarded = 0_usize;
+    while passed < cases {
+        let Some(t) = arbitrary.next() else {
+            break;
+        };
+        match property(&t) {
+            TestResult::Discard => {
+                discarded = discarded.saturating_add(1);
+                if discarded > max_discards {
+                    return Err(TooManyDiscards { discarded, passed });
+                }
+            }
+            TestResult::Fail(proof) => {
+                let failure = |candidate: &T| property(candidate).failure();
+                return Ok(Some(shrink::to_minimal_witness(&failure, t, proof)));
+            }
+            TestResult::Pass => passed = passed.saturating_add(1),
+        }
+    }
+    Ok(None)
+}
+
+/// Search for the smallest witness of an arbitrary property, if one exists,
+/// but check the property on at most `shrink_budget` candidates while shrinking.
+///
+/// Unlike `witness`, this reports whether shrinking finished:
+/// `Outcome::Partial` means a smaller witness may exist.
+#[inline]
+pub fn witness_outcome<T, Property, Proof>(
+    property: Property,
+    cases: usize,
+    shrink_budget: usize,
+    prng: &mut wyrand::WyRand,
+) -> Outcome<T, Proof>
+where
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let Ok(arbitrary) = arbitrary::arbitrary::<T>(prng) else {
+        return Outcome::NotFound;
+    };
+    for t in arbitrary.take(cases) {
+        if let Some(proof) = property(&t) {
+            let (witness, shrunk_proof, minimal) =
+                shrink::to_witness_within(&property, t, proof, shrink_budget, true);
+            return if minimal {
+                Outcome::Minimal(witness, shrunk_proof)
+            } else {
+                Outcome::Partial(witness, shrunk_proof)
+            };
+        }
+    }
+    Outcome::NotFound
+}
+
+/// Search for the smallest witness of an arbitrary property, if one exists,
+/// also returning the witness as originally generated, before any shrinking,
+/// so the two can be compared to see what shrinking removed.
+///
+/// Returns `(original, minimal, proof)`, where `proof` is the property's output on `minimal`.
+#[inline]
+pub fn witness_verbose<T, Property, Proof>(
+    property: Property,
+    cases: usize,
+    prng: &mut wyrand::WyRand,
+) -> Option<(T, T, Proof)>
+where
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let arbitrary = arbitrary::arbitrary::<T>(prng).ok()?;
+    for t in arbitrary.take(cases) {
+        if let Some(proof) = property(&t) {
+            let (minimal, minimal_proof) = shrink::to_minimal_witness(&property, t.clone(), proof);
+            return Some((t, minimal, minimal_proof));
+        }
+    }
+    None
+}
+
+/// Search for the smallest witness of an arbitrary property, if one exists,
+/// also reporting the generated value that came closest to being a witness.
+///
+/// `score` measures how close a value comes (higher is closer),
+/// so if the property barely holds, the second element shows where it almost didn't.
+/// Only generated values are scored, not shrinking candidates,
+/// and the earliest value wins ties.
+/// The second element is `None` only if no values were generated.
+#[inline]
+pub fn witness_scored<T, Property, Proof, Score>(
+    property: Property,
+    score: Score,
+    cases: usize,
+    prng: &mut wyrand::WyRand,
+) -> (Option<(T, Proof)>, Option<T>)
+where
+    Property: Fn(&T) -> Option<Proof>,
+    Score: Fn(&T) -> i64,
+    T: Pbt,
+{
+    let Ok(arbitrary) = arbitrary::arbitrary::<T>(prng) else {
+        return (None, None);
+    };
+    let mut best: Option<(i64, T)> = None;
+    for t in arbitrary.take(cases) {
+        let t_score = score(&t);
+        if best
+            .as_ref()
+            .is_none_or(|&(best_score, _)| t_score > best_score)
+        {
+            best = Some((t_score, t.clone()));
+        }
+        if let Some(proof) = property(&t) {
+            let minimal = shrink::to_minimal_witness(&property, t, proof);
+            return (Some(minimal), best.map(|(_, closest)| closest));
+        }
+    }
+    (None, best.map(|(_, closest)| closest))
+}
+
+/// Search for the smallest witness of an arbitrary property, if one exists,
+/// setting up a fresh environment (e.g. a temporary directory) for each evaluation.
+///
+/// Every call to the property, including while shrinking,
+/// receives the result of a new call to `setup`,
+/// which is passed to `teardown` as soon as the property returns or panics.
+#[inline]
+pub fn witness_with_env<T, Env, Setup, Teardown, Property, Proof>(
+    setup: Setup,
+    teardown: Teardown,
+    property: Property,
+    cases: usize,
+    prng: &mut wyrand::WyRand,
+) -> Option<(T, Proof)>
+where
+    Property: Fn(&Env, &T) -> Option<Proof>,
+    Setup: Fn() -> Env,
+    T: Pbt,
+    Teardown: Fn(Env),
+{
+    witness(
+        |t: &T| {
+            let environment = Environment {
+                env: Some(setup()),
+                teardown: &teardown,
+            };
+            property(environment.env.as_ref()?, t)
+        },
+        cases,
+        prng,
+    )
+}
+
+/// Search for the smallest witness of an arbitrary property, if one exists,
+/// drawing initial candidates from a bespoke generator
+/// rather than from `T`'s default distribution.
+///
+/// Witnesses may lie outside `T`'s default distribution,
+/// so they are neither replayed from nor persisted to `.pbt/`,
+/// and shrinking still uses `T`'s `Pbt` implementation.
+#[inline]
+pub fn witness_with_generator<T, G, Property, Proof>(
+    generator: &G,
+    property: Property,
+    cases: usize,
+    prng: &mut wyrand::WyRand,
+) -> Option<(T, Proof)>
+where
+    G: generator::Generator<T>,
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let () = reflection::register_globally::<T>();
+    for _ in 0..cases {
+        let t = generator.generate(prng);
+        if let Some(proof) = property(&t) {
+            return Some(shrink::to_minimal_witness_unpersisted(&property, t, proof));
+        }
+    }
+    None
+}
+
+/// Search for a witness of an arbitrary property, if one exists,
+/// shrinking toward values that minimize `distance` rather than toward the simplest value.
+///
+/// Shrinking can only reach values simpler than the original witness,
+/// so `distance` biases which of those values to prefer;
+/// it cannot move a witness toward something more complex.
+#[inline]
+pub fn witness_toward<T, Property, Proof, Distance>(
+    property: Property,
+    distance: Distance,
+    cases: usize,
+    prng: &mut wyrand::WyRand,
+) -> Option<(T, Proof)>
+where
+    Distance: Fn(&T) -> u64,
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let arbitrary = arbitrary::arbitrary::<T>(prng).ok()?;
+    for t in arbitrary.take(cases) {
+        if let Some(proof) = property(&t) {
+            return Some(shrink::to_witness_toward(&property, &distance, t, proof));
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use {super::*, pretty_assertions::assert_eq, wyrand::WyRand};
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        core::{cell::Cell, convert::Infallible, ops::ControlFlow, panic::AssertUnwindSafe},
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    /// Generate only even `u8`s.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "intentional: keep only the low bits"
+    )]
+    fn even_u8(prng: &mut WyRand) -> u8 {
+        (prng.rand() as u8) & !1
+    }
+
+    #[test]
+    fn cardinalities() {
+        assert_eq!(cardinality::<(bool, bool)>(), Some(4));
+        assert_eq!(cardinality::<Option<Infallible>>(), Some(1));
+        assert_eq!(cardinality::<u8>(), None);
+    }
+
+    #[test]
+    fn enumerations_are_consistent() {
+        let () = check_enumeration::<(bool, bool)>();
+        let () = check_enumeration::<Option<bool>>();
+        let () = check_enumeration::<ControlFlow<bool, Option<bool>>>();
+        let () = check_enumeration::<Option<Infallible>>();
+        let () = check_enumeration::<u8>();
+        let () = check_enumeration::<[bool; 40]>();
+    }
+
+    #[test]
+    fn scored_near_miss() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_scored(
+                |_: &u8| None::<()>,
+                |&i: &u8| i64::from(i),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            (None, Some(u8::MAX)),
+        );
+    }
+
+    #[test]
+    fn scored_no_cases() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_scored(|_: &u8| None::<()>, |_| 0, 0, &mut prng),
+            (None, None),
+        );
+    }
+
+    #[test]
+    fn env_setup_and_teardown_balance() {
+        let setups = Cell::new(0_usize);
+        let teardowns = Cell::new(0_usize);
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_with_env(
+                || {
+                    let () = setups.set(setups.get().saturating_add(1));
+                    200_u16
+                },
+                |_| teardowns.set(teardowns.get().saturating_add(1)),
+                |&threshold, i: &u16| i.checked_sub(threshold),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((200, 0)),
+        );
+        assert!(setups.get() > 0);
+        assert_eq!(setups.get(), teardowns.get());
+    }
+
+    #[test]
+    fn env_torn_down_after_panic() {
+        let setups = Cell::new(0_usize);
+        let teardowns = Cell::new(0_usize);
+        let result = panic::catch(AssertUnwindSafe(|| {
+            let mut prng = WyRand::new(42);
+            let _: Option<(u8, ())> = witness_with_env(
+                || setups.set(setups.get().saturating_add(1)),
+                |()| teardowns.set(teardowns.get().saturating_add(1)),
+                |&(), &i: &u8| {
+                    assert!(i < 200, "too big");
+                    None
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            );
+        }));
+        assert_eq!(result, Err(Some("too big".to_owned())));
+        assert!(setups.get() > 0);
+        assert_eq!(setups.get(), teardowns.get());
+    }
 
     #[test]
     fn witness_at_least_42() {
@@ -165,4 +817,229 @@ mod tests {
             Some((42, 0))
         );
     }
+
+    #[test]
+    fn exhaustive_bool_pairs() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_exhaustive(
+                |&(a, b): &(bool, bool)| (a && b).then_some(()),
+                4,
+                &mut prng,
+            ),
+            Some(((true, true), ())),
+        );
+        assert_eq!(
+            witness_exhaustive(
+                |&(a, b): &(bool, bool)| ((a & b) != (a && b)).then_some(()),
+                4,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn exhaustive_needle_in_haystack() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_exhaustive(
+                |bits: &[bool; 12]| (*bits == [true, false].repeat(6).as_slice()).then_some(()),
+                1 << 12,
+                &mut prng,
+            ),
+            Some(([true, false].repeat(6).try_into().unwrap(), ())),
+        );
+    }
+
+    #[test]
+    fn custom_generator_only_even() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_with_generator(
+                &even_u8,
+                |n: &u8| (n & 1 == 1).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn custom_generator_shrinks() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_with_generator(
+                &even_u8,
+                |n: &u8| n.checked_sub(100),
+                DEFAULT_N_CASES,
+                &mut prng
+            ),
+            Some((100, 0)),
+        );
+    }
+
+    #[test]
+    fn conditional_discards_odd() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_conditional(
+                |&n: &u16| {
+                    if n & 1 != 0 {
+                        TestResult::Discard
+                    } else if n == 42 {
+                        TestResult::Fail(())
+                    } else {
+                        TestResult::Pass
+                    }
+                },
+                DEFAULT_N_CASES,
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Ok(Some((42, ()))),
+        );
+    }
+
+    #[test]
+    fn conditional_too_many_discards() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_conditional(
+                |_: &u8| TestResult::<()>::Discard,
+                DEFAULT_N_CASES,
+                100,
+                &mut prng,
+            ),
+            Err(TooManyDiscards {
+                discarded: 101,
+                passed: 0,
+            }),
+        );
+    }
+
+    #[test]
+    fn env_overrides() {
+        assert_eq!(
+            parse_override::<u64>("PBT_SEED", Some("12345".to_owned())),
+            Some(12345),
+        );
+        assert_eq!(parse_override::<u64>("PBT_SEED", Some(String::new())), None);
+        assert_eq!(parse_override::<u64>("PBT_SEED", None), None);
+        assert_eq!(
+            parse_override::<usize>("PBT_ITERATIONS", Some("100".to_owned())),
+            Some(100),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`PBT_SEED` should be a number, but it's `-1`")]
+    fn env_override_out_of_range() {
+        let _: Option<u64> = parse_override("PBT_SEED", Some("-1".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "`PBT_ITERATIONS` should be a number, but it's `many`")]
+    fn env_override_unparseable() {
+        let _: Option<usize> = parse_override("PBT_ITERATIONS", Some("many".to_owned()));
+    }
+
+    #[test]
+    fn env_seed_reproduces_counterexamples() {
+        // SAFETY: No other test in this crate reads or writes `PBT_SEED`.
+        let () = unsafe { env::set_var("PBT_SEED", "12345") };
+        assert_eq!(seed(), 12345);
+        let original = || {
+            witness_verbose(
+                |&n: &u16| n.checked_sub(1000),
+                n_cases(),
+                &mut WyRand::new(seed()),
+            )
+            .map(|(original, _, _)| original)
+        };
+        let first = original().unwrap();
+        assert_eq!(original(), Some(first));
+    }
+
+    #[test]
+    fn outcome_minimal() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_outcome(
+                |&n: &u16| n.checked_sub(1000),
+                DEFAULT_N_CASES,
+                usize::MAX,
+                &mut prng
+            ),
+            Outcome::Minimal(1000, 0),
+        );
+    }
+
+    #[test]
+    fn outcome_not_found() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness_outcome(
+                |_: &bool| None::<()>,
+                DEFAULT_N_CASES,
+                usize::MAX,
+                &mut prng
+            ),
+            Outcome::NotFound,
+        );
+    }
+
+    #[test]
+    fn outcome_partial() {
+        let mut prng = WyRand::new(42);
+        let outcome = witness_outcome(
+            |&n: &u16| n.checked_sub(1000),
+            DEFAULT_N_CASES,
+            0,
+            &mut prng,
+        );
+        assert!(
+            matches!(outcome, Outcome::Partial(n, _) if n >= 1000),
+            "{outcome:?} should be a partially shrunk witness",
+        );
+    }
+
+    #[test]
+    fn sample_is_fresh_and_sized() {
+        let mut prng = WyRand::new(42);
+        let sampled: Vec<Option<bool>> = sample(5, &mut prng);
+        assert_eq!(sampled.len(), 5);
+        assert_eq!(sample::<Infallible>(5, &mut prng), vec![]);
+    }
+
+    #[test]
+    fn shrink_terminates() {
+        let () = check_shrink_termination(&(200_u8, 37_u8), 100);
+        let () = check_shrink_termination(&vec![255_u8, 0, 128, 7, 42], 100);
+        let () = check_shrink_termination(&Some(Some(u16::MAX)), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "took more than 1 steps")]
+    fn shrink_step_limit() {
+        let () = check_shrink_termination(&vec![255_u8, 0, 128], 1);
+    }
+
+    #[test]
+    fn toward_multiple_of_10() {
+        let mut prng = WyRand::new(42);
+        let (n, ()) = witness_toward(
+            |&n: &u32| (n > 1000).then_some(()),
+            |&n: &u32| {
+                let remainder = u64::from(n.rem_euclid(10));
+                remainder.min(10_u64.abs_diff(remainder))
+            },
+            DEFAULT_N_CASES,
+            &mut prng,
+        )
+        .unwrap();
+        assert!(n > 1000, "{n} should be a witness");
+        assert_eq!(n.rem_euclid(10), 0, "{n} should be a multiple of 10");
+    }
 }
@@ -0,0 +1,51 @@
+//! Platform-independent reduction of pseudorandom numbers into a range.
+
+use {core::num::NonZero, wyrand::WyRand};
+
+/// Choose an integer in `0..n` by reducing a full `u64` modulo `n`.
+///
+/// Casting to `usize` *before* reducing would truncate on 32-bit targets,
+/// so the same seed would produce different values on different platforms.
+/// Reducing first and casting after (which can't truncate, since the result is less than `n`)
+/// keeps seeds reproducible everywhere.
+#[inline]
+#[expect(
+    clippy::expect_used,
+    reason = "Internal invariants: violations should fail loudly."
+)]
+pub(crate) fn below(prng: &mut WyRand, n: NonZero<usize>) -> usize {
+    let random = prng.rand();
+    let Ok(modulus) = NonZero::<u64>::try_from(n) else {
+        // `n` exceeds every `u64`, so no reduction is necessary:
+        return usize::try_from(random).expect("INTERNAL ERROR (`pbt`): `u64` exceeds `usize`");
+    };
+    usize::try_from(random % modulus).expect("INTERNAL ERROR (`pbt`): remainder exceeds modulus")
+}
+
+/// Choose an element uniformly from a nonempty table.
+#[inline]
+pub(crate) fn pick<T, const N: usize>(prng: &mut WyRand, table: &[T; N]) -> T
+where
+    T: Copy,
+{
+    const { assert!(N > 0, "can't choose from an empty table") };
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "in bounds: reduced modulo the (nonzero) table length"
+    )]
+    table[below(prng, const { NonZero::new(N).unwrap() })]
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, core::iter, pretty_assertions::assert_eq};
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let n = const { NonZero::new(10).unwrap() };
+        let generated: Vec<usize> = iter::repeat_with(|| below(&mut prng, n)).take(10).collect();
+        let expected: Vec<usize> = vec![9, 2, 7, 2, 1, 3, 2, 4, 4, 5];
+        assert_eq!(generated, expected);
+    }
+}
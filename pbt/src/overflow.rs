@@ -0,0 +1,44 @@
+//! Integers biased toward their extremes, e.g. to hunt for overflow in checked arithmetic.
+
+use core::marker::PhantomData;
+
+/// An unsigned integer of type `Wide` biased toward the largest value of a narrower type `Target`,
+/// e.g. to hunt for truncation in `Target::try_from(wide)` or `wide as Target`.
+///
+/// Generation chooses the boundary `Target::MAX` or the first value past it,
+/// a value a small distance below or above the boundary,
+/// or, occasionally, a value uniformly from the whole range of `Wide`.
+/// Shrinking moves toward `0`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Narrow<Wide, Target>(Wide, PhantomData<Target>);
+
+impl<Wide, Target> Narrow<Wide, Target> {
+    /// The underlying integer.
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> Wide {
+        self.0
+    }
+
+    /// Wrap an integer.
+    #[inline]
+    #[must_use]
+    pub const fn new(wide: Wide) -> Self {
+        Self(wide, PhantomData)
+    }
+}
+
+/// An unsigned integer biased heavily toward `MAX` and `MIN` (i.e. `0`),
+/// so that sums and products overflow (or nearly overflow) often.
+///
+/// Generation chooses an edge case (`MAX`, `MAX - 1`, `0`, or `1`),
+/// a value a small distance below `MAX`,
+/// a value a small distance above `0`,
+/// or, occasionally, a value uniformly from the whole range.
+/// Shrinking moves toward `Overflowing(0)`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "a transparent newtype meant to be constructed directly"
+)]
+pub struct Overflowing<T>(pub T);
@@ -0,0 +1,181 @@
+//! Pairs of values that are often equal, e.g. to check that `Hash` agrees with `Eq`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+/// Two values of the same type, equal far more often than independent values would be.
+///
+/// Properties like "if `a == b` then `hash(a) == hash(b)`" say nothing
+/// about unequal pairs, and independent values are almost never equal,
+/// so generation chooses between two cases:
+/// one value cloned into both positions, or two independent values.
+/// Shrinking tries an equal pair before two independent values.
+///
+/// Every equal pair can be built either way, so `cardinality` and `enumerate`
+/// count it twice: e.g. `cardinality::<EqPair<bool>>()` is `Some(6)`, not `Some(4)`,
+/// and `check_enumeration` rejects this type.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "a transparent pair meant to be constructed and destructured directly"
+)]
+pub struct EqPair<T>(pub T, pub T);
+
+impl<T> Pbt for EqPair<T>
+where
+    T: PartialEq + Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`EqPair` is not a literal").get();
+        match algebraic_index {
+            1 => {
+                let t: T = fields.field();
+                Self(t.clone(), t)
+            }
+            2 => Self(fields.field(), fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `EqPair`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let Self(lhs, rhs) = self;
+        let variant_index = if lhs == rhs {
+            let () = fields.push(lhs);
+            const { NonZero::new(1).unwrap() }
+        } else {
+            // Fields are popped in reverse:
+            let () = fields.push(rhs);
+            let () = fields.push(lhs);
+            const { NonZero::new(2).unwrap() }
+        };
+        Parts {
+            fields,
+            variant_index: Some(variant_index),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: iter::once(TypeId::of::<T>()).collect(),
+            },
+            Variant {
+                field_types: iter::repeat_n(TypeId::of::<T>(), 2).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_eta_expansion,
+            check_serialization, enumerate, persist, reflection::register_globally, sample,
+            witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<EqPair<u8>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<EqPair<u8>> = persist::replay();
+        let () = expected.extend([
+            EqPair(200, 6),
+            EqPair(108, 108),
+            EqPair(0, 0),
+            EqPair(14, 14),
+            EqPair(0, 0),
+            EqPair(3, 3),
+            EqPair(211, 0),
+            EqPair(89, 89),
+            EqPair(1, 5),
+            EqPair(202, 202),
+        ]);
+        let generated: Vec<EqPair<u8>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration_repeats_equal_pairs() {
+        assert_eq!(cardinality::<EqPair<bool>>(), Some(6));
+        assert_eq!(
+            enumerate::<EqPair<bool>>().map(Iterator::collect),
+            Some(vec![
+                EqPair(false, false),
+                EqPair(true, true),
+                EqPair(false, false),
+                EqPair(false, true),
+                EqPair(true, false),
+                EqPair(true, true),
+            ]),
+        );
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<EqPair<usize>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<EqPair<usize>>();
+    }
+
+    #[test]
+    fn generates_equal_and_unequal_pairs() {
+        let mut prng = WyRand::new(42);
+        let pairs: Vec<EqPair<u64>> = sample(DEFAULT_N_CASES, &mut prng);
+        let n_equal = pairs.iter().filter(|&&EqPair(lhs, rhs)| lhs == rhs).count();
+        assert!(
+            n_equal > DEFAULT_N_CASES >> 3_u32,
+            "only {n_equal} equal pairs"
+        );
+        assert!(n_equal < DEFAULT_N_CASES, "no unequal pairs");
+    }
+
+    #[test]
+    fn minimal_unequal() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&EqPair(lhs, rhs): &EqPair<u8>| (lhs != rhs).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((EqPair(0, 1), ())),
+        );
+    }
+}
@@ -73,6 +73,25 @@ where
     dir().join(jsonl_filename)
 }
 
+/// Parse one line of persisted JSONL.
+///
+/// Without a recursion limit, since e.g. a long linked list nests deeper than the default 128
+/// and would otherwise be persisted but never replayed.
+#[inline]
+#[expect(
+    clippy::expect_used,
+    reason = "Internal invariants: violations should fail loudly."
+)]
+fn parse(line: &str) -> serde_json::Value {
+    let mut deserializer = serde_json::Deserializer::from_str(line);
+    let () = deserializer.disable_recursion_limit();
+    deserializer
+        .into_iter()
+        .next()
+        .and_then(Result::ok)
+        .expect("INTERNAL ERROR (`pbt`): couldn't parse persisted JSONL")
+}
+
 /// Replay any witnesses persisted for this type.
 ///
 /// The corpus is read into memory before this returns, so its shared filesystem
@@ -91,6 +110,7 @@ where
 )]
 #[expect(
     clippy::absolute_paths,
+    clippy::std_instead_of_core,
     reason = "`core::io::ErrorKind` is unstable; filesystem errors use stable `std::io`."
 )]
 pub fn replay<T>() -> Vec<T>
@@ -113,8 +133,7 @@ where
                     .map(|line_result| {
                         let line = line_result
                             .expect("INTERNAL ERROR (`pbt`): couldn't read persisted witnesses");
-                        let json = serde_json::from_str(&line)
-                            .expect("INTERNAL ERROR (`pbt`): couldn't parse persisted JSONL");
+                        let json = parse(&line);
                         Parts::deserialize(&json).expect(
                             "INTERNAL ERROR (`pbt`): couldn't deserialize a persisted witness",
                         )
@@ -165,9 +184,7 @@ where
 
     for line_result in BufReader::new(&file).lines() {
         let line = line_result.expect("INTERNAL ERROR (`pbt`): couldn't read persisted witnesses");
-        let persisted: serde_json::Value = serde_json::from_str(&line)
-            .expect("INTERNAL ERROR (`pbt`): couldn't parse persisted JSONL");
-        if persisted == json {
+        if parse(&line) == json {
             return;
         }
     }
@@ -183,6 +200,10 @@ mod tests {
         clippy::expect_used,
         reason = "Subprocess and filesystem failures should fail tests loudly."
     )]
+    #![expect(
+        clippy::std_instead_of_core,
+        reason = "`core::io::ErrorKind` is unstable; filesystem errors use stable `std::io`."
+    )]
 
     use {
         super::*,
@@ -0,0 +1,161 @@
+//! Stand-ins for process-level data, e.g. exit codes, without spawning processes.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::iter,
+};
+
+/// Exit codes likely to trip up code that categorizes them.
+const EDGE_CASES: [u8; 4] = [0, 1, 2, 255];
+
+/// A process's exit code, as reported on Unix: `0` for success, anything else for failure.
+///
+/// Generation either chooses uniformly
+/// or chooses an edge case: `0` (success), `1` (general failure),
+/// `2` (misuse, by convention), or `255` (the largest).
+/// Shrinking moves toward `ExitCode(0)`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(clippy::exhaustive_structs, reason = "every `u8` is a valid exit code")]
+pub struct ExitCode(pub u8);
+
+impl ExitCode {
+    /// Whether this code reports success, i.e. is `0`.
+    #[inline]
+    #[must_use]
+    pub const fn is_success(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Pbt for ExitCode {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`ExitCode` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                s.parse().ok().map(Self)
+            },
+            generators: vec![
+                #[expect(
+                    clippy::as_conversions,
+                    clippy::cast_possible_truncation,
+                    reason = "intentional: keep only the low bits"
+                )]
+                |prng| Self(prng.rand() as u8),
+                |prng| Self(modulo::pick(prng, &EDGE_CASES)),
+            ],
+            serialize: |&Self(code)| code.to_string().into(),
+            shrink: |Self(code)| Box::new((shrink!(u8))(code).map(Self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            check_shrink_termination, persist, reflection::register_globally, shrink, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<ExitCode>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<ExitCode> = persist::replay();
+        let () = expected.extend([2, 2, 2, 230, 88, 168, 2, 1, 0, 1].map(ExitCode));
+        let generated: Vec<ExitCode> = arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn deterministic_shrink() {
+        let () = register_globally::<ExitCode>();
+        let shrunk: Vec<u8> = shrink::candidates(ExitCode(255))
+            .map(|ExitCode(code)| code)
+            .collect();
+        let expected: Vec<u8> = vec![0, 128, 192, 224, 240, 248, 252, 254];
+        assert_eq!(shrunk, expected);
+        let () = check_shrink_termination(&ExitCode(255), 100);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<ExitCode>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<ExitCode>();
+    }
+
+    #[test]
+    fn generates_every_edge_case() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<ExitCode> = arbitrary(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .collect();
+        for code in EDGE_CASES {
+            assert!(
+                generated.contains(&ExitCode(code)),
+                "never generated `ExitCode({code})`",
+            );
+        }
+    }
+
+    #[test]
+    fn minimal_miscategorized() {
+        // A handler that checks only the low bit mistakes even failures for success:
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&code: &ExitCode| {
+                    let handler_says_success = code.0 & 1 == 0;
+                    (handler_says_success != code.is_success()).then_some(())
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((ExitCode(2), ())),
+        );
+    }
+}
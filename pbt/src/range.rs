@@ -0,0 +1,144 @@
+//! Support for `#[pbt(range = "LO..=HI")]` on integer fields of derived types.
+//!
+//! The derive stores each ranged field as a `Bounded<LO, HI>`,
+//! so generation and shrinking never leave the range,
+//! then converts to and from the field's own type at the edges.
+//! A range that doesn't fit in the field's type is a compile-time error (see `check`).
+
+use crate::bounded::Bounded;
+
+/// Implement `Integer` for each of a list of primitive integer types.
+macro_rules! impl_integer {
+    ($($t:ty),* $(,)?) => {
+        $(
+            #[allow(
+                clippy::allow_attributes,
+                clippy::as_conversions,
+                clippy::cast_lossless,
+                clippy::cast_possible_wrap,
+                clippy::cast_sign_loss,
+                reason = "lossless: `MIN` fits in `i128`, and `MAX` is positive and fits in `u128`"
+            )]
+            impl Integer for $t {
+                const WIDE_MAX: u128 = <$t>::MAX as u128;
+                const WIDE_MIN: i128 = <$t>::MIN as i128;
+            }
+        )*
+    };
+}
+
+/// Integer types whose fields can carry `#[pbt(range = "...")]`,
+/// with their bounds widened losslessly so that any two can be compared in a `const`.
+pub trait Integer: TryFrom<i64> + TryInto<i64> {
+    /// The largest value of this type.
+    const WIDE_MAX: u128;
+    /// The smallest value of this type.
+    const WIDE_MIN: i128;
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Fail (at compile time, when evaluated in a `const`)
+/// unless every integer in `LO..=HI` fits in `T`.
+///
+/// `#[derive(Pbt)]` emits `const _: () = check::<T, LO, HI>();` for each ranged field,
+/// so a range too wide for its field doesn't compile:
+/// ```compile_fail
+/// #[derive(Clone, Debug, pbt::Pbt)]
+/// struct TooWide(#[pbt(range = "0..=300")] u8);
+/// ```
+///
+/// # Panics
+///
+/// If the range doesn't fit in `T`.
+#[inline]
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_sign_loss,
+    reason = "lossless: widening an `i64` to `i128`, or a nonnegative `i64` to `u128`"
+)]
+pub const fn check<T, const LO: i64, const HI: i64>()
+where
+    T: Integer,
+{
+    assert!(
+        (LO >= 0 || LO as i128 >= T::WIDE_MIN) && (HI < 0 || HI as u128 <= T::WIDE_MAX),
+        "`#[pbt(range = \"...\")]` doesn't fit in the field's type",
+    );
+}
+
+/// Convert a bounded integer into a ranged field's type.
+///
+/// # Panics
+///
+/// If the range doesn't fit in the field's type, e.g. `#[pbt(range = "0..=1000")]` on a `u8`,
+/// which `#[derive(Pbt)]` already rejects at compile time (see `check`).
+#[inline]
+#[must_use]
+#[expect(clippy::panic, reason = "misconfiguration should fail loudly")]
+pub fn narrow<T, const LO: i64, const HI: i64>(bounded: Bounded<LO, HI>) -> T
+where
+    T: TryFrom<i64>,
+{
+    let i = bounded.get();
+    T::try_from(i).unwrap_or_else(|_| {
+        panic!("`#[pbt(range = \"{LO}..={HI}\")]` includes `{i}`, which doesn't fit in the field's type")
+    })
+}
+
+/// Convert a ranged field into a bounded integer,
+/// clamping (e.g. if the field was modified by hand) to the nearest end of the range.
+#[inline]
+#[must_use]
+pub fn widen<T, const LO: i64, const HI: i64>(field: T) -> Bounded<LO, HI>
+where
+    T: TryInto<i64>,
+{
+    // Integers no wider than `i64` fail to convert only if they're too large:
+    Bounded::clamped(field.try_into().unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, pretty_assertions::assert_eq};
+
+    #[test]
+    fn round_trip() {
+        let bounded: Bounded<0, 100> = widen(42_u8);
+        assert_eq!(narrow::<u8, 0, 100>(bounded), 42);
+    }
+
+    #[test]
+    fn widen_clamps() {
+        assert_eq!(widen::<u8, 10, 20>(5).get(), 10);
+        assert_eq!(widen::<u64, 10, 20>(u64::MAX).get(), 20);
+    }
+
+    #[test]
+    fn check_accepts_ranges_that_fit() {
+        let () = const { check::<u8, 0, 255>() };
+        let () = const { check::<i8, -128, 127>() };
+        let () = const { check::<u64, 0, { i64::MAX }>() };
+        let () = const { check::<i128, { i64::MIN }, { i64::MAX }>() };
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit in the field's type")]
+    fn check_rejects_too_high() {
+        let () = check::<u8, 0, 300>();
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit in the field's type")]
+    fn check_rejects_too_low() {
+        let () = check::<u16, -1, 10>();
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit in the field's type")]
+    fn narrow_out_of_type() {
+        let _narrowed: u8 = narrow(widen::<u16, 0, 1000>(1000));
+    }
+}
@@ -20,7 +20,7 @@ use {
     alloc::{collections::BTreeMap, sync::Arc},
     core::{
         any::{TypeId, type_name},
-        cmp,
+        cmp, error, fmt,
         hash::{Hash, Hasher},
         iter, mem,
         num::NonZero,
@@ -687,6 +687,15 @@ impl Parts<Store> {
     }
 }
 
+impl fmt::Display for Uninstantiable {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("type is uninstantiable with finite memory")
+    }
+}
+
+impl error::Error for Uninstantiable {}
+
 impl<SelfType> Variants<SelfType> {
     /// Erase type data while maintaining exactly the same function pointers.
     #[inline]
@@ -873,3 +882,16 @@ unsafe fn drop_typed<T>(pointer: ptr::NonNull<u8>, length: usize, capacity: usiz
     let typed = unsafe { Vec::from_raw_parts(pointer.cast::<T>().as_ptr(), length, capacity) };
     let () = drop(typed);
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, pretty_assertions::assert_eq};
+
+    #[test]
+    fn uninstantiable_display() {
+        assert_eq!(
+            Uninstantiable.to_string(),
+            "type is uninstantiable with finite memory",
+        );
+    }
+}
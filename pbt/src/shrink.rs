@@ -167,7 +167,9 @@ impl ShrinkingCache {
         let erased_vec_ops = erased_vec_ops_of(ty);
         // Clone `erased_boxed` before moving it into `erased_vec_ops.shrink`:
         let original = (erased_vec_ops.clone)(erased_boxed);
-        let iterator = (erased_vec_ops.shrink)(erased_boxed);
+        // Fused since polling an exhausted `EachField` widens its leash and starts over,
+        // which (nested once per level of a recursive type) takes exponential time:
+        let iterator = Box::new((erased_vec_ops.shrink)(erased_boxed).fuse());
         Self {
             erased_vec_ops,
             cache: (erased_vec_ops.empty)(),
@@ -259,22 +261,119 @@ where
 /// starting from a witness that is probably far larger than necessary.
 #[inline]
 pub(crate) fn to_minimal_witness<T, Property, Proof>(
+    property: &Property,
+    best_yet: T,
+    proof: Proof,
+) -> (T, Proof)
+where
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let (witness, minimal_proof, _) =
+        to_witness_within(property, best_yet, proof, usize::MAX, true);
+    (witness, minimal_proof)
+}
+
+/// Like `to_minimal_witness`, but without persisting the result,
+/// e.g. for witnesses drawn from outside `T`'s default distribution.
+#[inline]
+pub(crate) fn to_minimal_witness_unpersisted<T, Property, Proof>(
+    property: &Property,
+    best_yet: T,
+    proof: Proof,
+) -> (T, Proof)
+where
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let (witness, minimal_proof, _) =
+        to_witness_within(property, best_yet, proof, usize::MAX, false);
+    (witness, minimal_proof)
+}
+
+/// Like `to_minimal_witness`, but give up after checking
+/// the property on `budget` shrinking candidates.
+///
+/// The `bool` is whether shrinking finished,
+/// i.e. whether no candidate of the returned witness satisfies the property.
+/// If `persist` is set, the returned witness is saved for later runs.
+#[inline]
+pub(crate) fn to_witness_within<T, Property, Proof>(
     property: &Property,
     mut best_yet: T,
     mut proof: Proof,
-) -> (T, Proof)
+    mut budget: usize,
+    persist: bool,
+) -> (T, Proof, bool)
 where
     Property: Fn(&T) -> Option<Proof>,
     T: Pbt,
 {
     'giant_leaps: loop {
         for candidate in candidates::<T>(best_yet.clone()) {
+            let Some(decremented) = budget.checked_sub(1) else {
+                if persist {
+                    let () = persist::witness(&best_yet);
+                }
+                return (best_yet, proof, false);
+            };
+            budget = decremented;
             if let Some(next_proof) = property(&candidate) {
                 best_yet = candidate;
                 proof = next_proof;
                 continue 'giant_leaps;
             }
         }
+        if persist {
+            let () = persist::witness(&best_yet);
+        }
+        return (best_yet, proof, true);
+    }
+}
+
+/// Like `to_minimal_witness`, but prefer candidates closer to some target,
+/// as measured by a user-supplied distance.
+///
+/// A failing candidate strictly closer than the best witness yet is taken immediately;
+/// otherwise, the first failing candidate at the same distance is taken after the pass,
+/// so distance never increases and ties shrink as usual.
+#[inline]
+pub(crate) fn to_witness_toward<T, Property, Proof, Distance>(
+    property: &Property,
+    distance: &Distance,
+    mut best_yet: T,
+    mut proof: Proof,
+) -> (T, Proof)
+where
+    Distance: Fn(&T) -> u64,
+    Property: Fn(&T) -> Option<Proof>,
+    T: Pbt,
+{
+    let mut best_distance = distance(&best_yet);
+    'giant_leaps: loop {
+        let mut tie = None;
+        for candidate in candidates::<T>(best_yet.clone()) {
+            let candidate_distance = distance(&candidate);
+            if candidate_distance > best_distance
+                || (candidate_distance == best_distance && tie.is_some())
+            {
+                continue;
+            }
+            if let Some(next_proof) = property(&candidate) {
+                if candidate_distance < best_distance {
+                    best_yet = candidate;
+                    best_distance = candidate_distance;
+                    proof = next_proof;
+                    continue 'giant_leaps;
+                }
+                tie = Some((candidate, next_proof));
+            }
+        }
+        if let Some((candidate, next_proof)) = tie {
+            best_yet = candidate;
+            proof = next_proof;
+            continue 'giant_leaps;
+        }
         let () = persist::witness(&best_yet);
         return (best_yet, proof);
     }
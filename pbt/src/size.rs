@@ -2,6 +2,7 @@
 //! counting only inductive types and ignoring leaves.
 
 use {
+    crate::modulo,
     alloc::collections::BinaryHeap,
     core::{cmp, iter, mem, num::NonZero},
     wyrand::WyRand,
@@ -74,6 +75,12 @@ impl Size {
         })
     }
 
+    /// A specific size, e.g. chosen by a fuzzer rather than by `increasing`.
+    #[inline]
+    pub(crate) const fn new(total: usize) -> Self {
+        Self { total }
+    }
+
     /// Partition this size into a known number of sizes
     /// which add up to the same size we started with.
     ///
@@ -109,17 +116,12 @@ impl Size {
             .expect("PSA from `pbt`: your memory will not hold a term of size `usize::MAX`.");
         // SAFETY: Incremented above, starting from at least zero,
         // so the result must be at least 1.
-        let modulo = unsafe { NonZero::new_unchecked(incremented) };
+        let modulus = unsafe { NonZero::new_unchecked(incremented) };
 
         // SAFETY: Nonzero. Checked above.
         let n_separators = unsafe { into_how_many.unchecked_sub(1) };
-        #[expect(
-            clippy::as_conversions,
-            clippy::cast_possible_truncation,
-            reason = "intentional"
-        )]
         let separators = Some({
-            iter::repeat_with(|| cmp::Reverse(prng.rand() as usize % modulo))
+            iter::repeat_with(|| cmp::Reverse(modulo::below(prng, modulus)))
                 .take(n_separators)
                 .collect()
         });
@@ -134,11 +136,6 @@ impl Size {
     /// Based on the size we have left, should we
     /// head toward a leaf or recurse again?
     #[inline]
-    #[expect(
-        clippy::as_conversions,
-        clippy::cast_possible_truncation,
-        reason = "OK: `u64` is already huge"
-    )]
     #[expect(
         clippy::expect_used,
         reason = "Internal invariants: violations should fail loudly."
@@ -152,7 +149,7 @@ impl Size {
                 ))
             };
 
-        (prng.rand() as usize % incremented) != 0
+        modulo::below(prng, incremented) != 0
     }
 
     /// A total size of zero.
@@ -0,0 +1,271 @@
+//! Arbitrary orderings, e.g. to test that a sort respects any valid comparator.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        modulo,
+        reflection::{Parts, Variants},
+        registration::Registration,
+    },
+    core::{array, cmp::Ordering, iter, num::NonZero},
+    wyrand::WyRand,
+};
+
+/// A total order over the indices `0..N`, represented as a permutation:
+/// index `i` has rank `ranks()[i]`.
+///
+/// Generation either shuffles uniformly
+/// or chooses an edge case: the identity or the reverse ordering.
+/// Shrinking swaps adjacent out-of-order ranks,
+/// so it moves toward the identity one inversion at a time.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Comparator<const N: usize>([usize; N]);
+
+impl<const N: usize> Comparator<N> {
+    /// Compare two indices according to this ordering.
+    ///
+    /// # Panics
+    ///
+    /// If either index is at least `N`.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "documented panic on out-of-range indices"
+    )]
+    pub fn compare(&self, lhs: usize, rhs: usize) -> Ordering {
+        self.0[lhs].cmp(&self.0[rhs])
+    }
+
+    /// The ordering that compares indices as integers.
+    #[inline]
+    #[must_use]
+    pub fn identity() -> Self {
+        Self(array::from_fn(|i| i))
+    }
+
+    /// Wrap a rank for each index if and only if they form a permutation of `0..N`.
+    #[inline]
+    #[must_use]
+    pub fn new(ranks: [usize; N]) -> Option<Self> {
+        let mut seen = [false; N];
+        for rank in ranks {
+            let slot = seen.get_mut(rank)?;
+            if *slot {
+                return None;
+            }
+            *slot = true;
+        }
+        Some(Self(ranks))
+    }
+
+    /// The rank of each index: lower ranks compare less.
+    #[inline]
+    #[must_use]
+    pub const fn ranks(&self) -> &[usize; N] {
+        &self.0
+    }
+
+    /// The ordering that compares indices as integers, but backward.
+    #[inline]
+    #[must_use]
+    pub fn reverse() -> Self {
+        Self(array::from_fn(|i| N.saturating_sub(i).saturating_sub(1)))
+    }
+}
+
+impl<const N: usize> Pbt for Comparator<N> {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Comparator` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::Array(ref json_ranks) = *json else {
+                    return None;
+                };
+                let ranks: Vec<usize> = json_ranks
+                    .iter()
+                    .map(|rank| usize::try_from(rank.as_u64()?).ok())
+                    .collect::<Option<_>>()?;
+                Self::new(ranks.try_into().ok()?)
+            },
+            generators: vec![shuffle, |_| Self::identity(), |_| Self::reverse()],
+            serialize: |&Self(ranks)| ranks.iter().copied().collect(),
+            shrink,
+        }
+    }
+}
+
+/// Swap each adjacent pair of out-of-order ranks, removing exactly one inversion apiece.
+#[inline]
+fn shrink<const N: usize>(
+    Comparator(ranks): Comparator<N>,
+) -> Box<dyn Iterator<Item = Comparator<N>>> {
+    Box::new(
+        iter::zip(0..N, 1..N)
+            .filter(move |&(i, j)| ranks.get(i) > ranks.get(j))
+            .map(move |(i, j)| {
+                let mut swapped = ranks;
+                let () = swapped.swap(i, j);
+                Comparator(swapped)
+            }),
+    )
+}
+
+/// Choose a permutation uniformly (via a Fisher-Yates shuffle).
+#[inline]
+fn shuffle<const N: usize>(prng: &mut WyRand) -> Comparator<N> {
+    let mut ranks: [usize; N] = array::from_fn(|i| i);
+    for i in (1..N).rev() {
+        let j = modulo::below(prng, NonZero::<usize>::MIN.saturating_add(i));
+        let () = ranks.swap(i, j);
+    }
+    Comparator(ranks)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    /// A single bubble-sort pass, which is not enough to sort in general.
+    #[expect(clippy::indexing_slicing, reason = "in bounds: `i < j < N`")]
+    fn one_bubble_pass<const N: usize>(comparator: &Comparator<N>) -> [usize; N] {
+        let mut indices: [usize; N] = array::from_fn(|i| i);
+        for (i, j) in iter::zip(0..N, 1..N) {
+            if comparator.compare(indices[i], indices[j]) == Ordering::Greater {
+                let () = indices.swap(i, j);
+            }
+        }
+        indices
+    }
+
+    #[test]
+    fn deterministic() {
+        let () = register_globally::<Comparator<4>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Comparator<4>> = persist::replay();
+        let () = expected.extend(
+            [
+                [0, 1, 2, 3],
+                [0, 1, 2, 3],
+                [2, 0, 3, 1],
+                [0, 1, 2, 3],
+                [1, 3, 2, 0],
+                [3, 0, 2, 1],
+                [3, 2, 1, 0],
+                [3, 2, 1, 0],
+                [3, 2, 1, 0],
+                [3, 2, 1, 0],
+            ]
+            .map(Comparator),
+        );
+        let generated: Vec<Comparator<4>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn deterministic_shrink() {
+        let shrunk: Vec<[usize; 4]> = shrink(Comparator([3, 1, 2, 0]))
+            .map(|comparator| *comparator.ranks())
+            .collect();
+        let expected: Vec<[usize; 4]> = vec![[1, 3, 2, 0], [3, 1, 0, 2]];
+        assert_eq!(shrunk, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Comparator<5>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Comparator<5>>();
+    }
+
+    #[test]
+    fn new_rejects_non_permutations() {
+        assert_eq!(Comparator::new([0, 0, 1]), None);
+        assert_eq!(Comparator::new([0, 1, 3]), None);
+        assert_eq!(Comparator::new([2, 0, 1]), Some(Comparator([2, 0, 1])));
+    }
+
+    #[test]
+    fn reverse_reverses() {
+        assert_eq!(Comparator::<4>::reverse(), Comparator([3, 2, 1, 0]));
+        assert_eq!(Comparator::<0>::reverse(), Comparator([]));
+    }
+
+    #[test]
+    fn sort_by_respects_any_comparator() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |comparator: &Comparator<6>| {
+                    let mut sorted: [usize; 6] = array::from_fn(|i| i);
+                    let () = sorted.sort_by(|&lhs, &rhs| comparator.compare(lhs, rhs));
+                    sorted
+                        .windows(2)
+                        .any(|pair| matches!(*pair, [lhs, rhs] if comparator.compare(lhs, rhs) != Ordering::Less))
+                        .then_some(sorted)
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn buggy_sort() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |comparator: &Comparator<4>| {
+                    let sorted = one_bubble_pass(comparator);
+                    sorted
+                        .windows(2)
+                        .any(|pair| matches!(*pair, [lhs, rhs] if comparator.compare(lhs, rhs) == Ordering::Greater))
+                        .then_some(sorted)
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Comparator([0, 2, 3, 1]), [0, 1, 3, 2])),
+        );
+    }
+}
@@ -0,0 +1,574 @@
+//! Values with an ordering invariant, e.g. a set represented as a sorted `Vec`.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{
+        any::TypeId,
+        iter,
+        num::NonZero,
+        ops::{Bound, RangeBounds},
+    },
+};
+
+/// A pair of values in order: `.lower() <= .upper()`.
+///
+/// Generation and shrinking both operate on an ordinary `(T, T)`,
+/// which is then swapped if out of order,
+/// so no generated or shrunk value ever violates the invariant.
+///
+/// Every pair of distinct values can be built in either order, so `cardinality` and `enumerate`
+/// count it twice: e.g. `cardinality::<Ordered<bool>>()` is `Some(4)`, not `Some(3)`,
+/// and `check_enumeration` rejects this type.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ordered<T>(T, T)
+where
+    T: Ord;
+
+/// A range that range-query APIs (e.g. `BTreeMap::range`) accept without panicking:
+/// its start is no greater than its end,
+/// and it's never excluded at both ends at the same value.
+///
+/// Generation and shrinking both operate on an ordinary `(Bound<T>, Bound<T>)`,
+/// whose bounds are then swapped if out of order,
+/// and `Excluded(x)..Excluded(x)` becomes the empty range `Included(x)..Excluded(x)`,
+/// so no generated or shrunk value ever violates the invariant.
+///
+/// Many ranges can be built from more than one pair of bounds,
+/// so `cardinality` and `enumerate` count them more than once:
+/// e.g. `cardinality::<ValidRange<bool>>()` is `Some(25)`, not `Some(19)`,
+/// and `check_enumeration` rejects this type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ValidRange<T>(Bound<T>, Bound<T>)
+where
+    T: Ord;
+
+/// A strictly increasing `Vec`: sorted, with no duplicates.
+///
+/// Generation and shrinking both operate on an ordinary `Vec`,
+/// which is then sorted and deduplicated,
+/// so no generated or shrunk value ever violates the invariant.
+/// Shrinking removes elements (and shrinks those that remain).
+///
+/// As for any `Vec`, `cardinality` and `enumerate` return `None`,
+/// even though, e.g., `SortedUnique<bool>` has only four values.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`SortedUnique` reads better than `sorted::Unique`"
+)]
+pub struct SortedUnique<T>(Vec<T>)
+where
+    T: Ord;
+
+impl<T> Ordered<T>
+where
+    T: Ord,
+{
+    /// Unwrap both values, lesser first.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (T, T) {
+        (self.0, self.1)
+    }
+
+    /// The lesser value.
+    #[inline]
+    #[must_use]
+    pub const fn lower(&self) -> &T {
+        &self.0
+    }
+
+    /// Order any two values, swapping them if necessary.
+    #[inline]
+    #[must_use]
+    pub fn new(lhs: T, rhs: T) -> Self {
+        if lhs <= rhs {
+            Self(lhs, rhs)
+        } else {
+            Self(rhs, lhs)
+        }
+    }
+
+    /// The greater value.
+    #[inline]
+    #[must_use]
+    pub const fn upper(&self) -> &T {
+        &self.1
+    }
+}
+
+impl<T> SortedUnique<T>
+where
+    T: Ord,
+{
+    /// The underlying elements, in strictly increasing order.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Unwrap the underlying elements, in strictly increasing order.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Sort and deduplicate any `Vec`.
+    #[inline]
+    #[must_use]
+    pub fn new(mut elements: Vec<T>) -> Self {
+        let () = elements.sort_unstable();
+        let () = elements.dedup();
+        Self(elements)
+    }
+}
+
+impl<T> ValidRange<T>
+where
+    T: Ord,
+{
+    /// Unwrap both bounds, start first.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (Bound<T>, Bound<T>) {
+        (self.0, self.1)
+    }
+
+    /// Make any two bounds valid, swapping them or including the start if necessary.
+    #[inline]
+    #[must_use]
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        let out_of_order = matches!(
+            (&start, &end),
+            (
+                &(Bound::Included(ref lhs) | Bound::Excluded(ref lhs)),
+                &(Bound::Included(ref rhs) | Bound::Excluded(ref rhs)),
+            ) if lhs > rhs,
+        );
+        let ordered = if out_of_order {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        match ordered {
+            (Bound::Excluded(lhs), Bound::Excluded(rhs)) if lhs == rhs => {
+                Self(Bound::Included(lhs), Bound::Excluded(rhs))
+            }
+            (lower, upper) => Self(lower, upper),
+        }
+    }
+}
+
+impl<T> RangeBounds<T> for ValidRange<T>
+where
+    T: Ord,
+{
+    #[inline]
+    fn end_bound(&self) -> Bound<&T> {
+        self.1.as_ref()
+    }
+
+    #[inline]
+    fn start_bound(&self) -> Bound<&T> {
+        self.0.as_ref()
+    }
+}
+
+impl<T> Pbt for Ordered<T>
+where
+    T: Ord + Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Ordered` is not a literal").get();
+        match algebraic_index {
+            1 => {
+                let (lhs, rhs) = fields.field();
+                Self::new(lhs, rhs)
+            }
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Ordered`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.into_inner());
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<(T, T)>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<(T, T)>()).collect(),
+        }])
+    }
+}
+
+impl<T> Pbt for ValidRange<T>
+where
+    T: Ord + Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`ValidRange` is not a literal").get();
+        match algebraic_index {
+            1 => {
+                let (start, end) = fields.field();
+                Self::new(start, end)
+            }
+            _ => panic!("can't instantiate variant #{algebraic_index} of `ValidRange`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.into_inner());
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<(Bound<T>, Bound<T>)>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<(Bound<T>, Bound<T>)>()).collect(),
+        }])
+    }
+}
+
+impl<T> Pbt for SortedUnique<T>
+where
+    T: Ord + Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index
+            .expect("`SortedUnique` is not a literal")
+            .get();
+        match algebraic_index {
+            1 => Self::new(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `SortedUnique`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.0);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<Vec<T>>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<Vec<T>>()).collect(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_eta_expansion,
+            check_serialization, enumerate, persist, reflection::register_globally, witness,
+        },
+        alloc::collections::BTreeSet,
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    /// Whether each element is strictly less than the next.
+    fn strictly_increasing(elements: &[u8]) -> bool {
+        elements
+            .windows(2)
+            .all(|pair| matches!(*pair, [lhs, rhs] if lhs < rhs))
+    }
+
+    #[test]
+    fn ordered_deterministic() {
+        let () = register_globally::<Ordered<u8>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<Ordered<u8>> = persist::replay();
+        let () = expected.extend([
+            Ordered(0, 9),
+            Ordered(1, 150),
+            Ordered(1, 229),
+            Ordered(168, 243),
+            Ordered(206, 222),
+            Ordered(117, 166),
+            Ordered(0, 147),
+            Ordered(0, 1),
+            Ordered(0, 51),
+            Ordered(0, 122),
+        ]);
+        let generated: Vec<Ordered<u8>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn ordered_enumeration_repeats_distinct_pairs() {
+        assert_eq!(cardinality::<Ordered<bool>>(), Some(4));
+        assert_eq!(
+            enumerate::<Ordered<bool>>().map(|pairs| pairs.map(Ordered::into_inner).collect()),
+            Some(vec![
+                (false, false),
+                (false, true),
+                (false, true),
+                (true, true)
+            ]),
+        );
+    }
+
+    #[test]
+    fn ordered_eta_expansion() {
+        let () = check_eta_expansion::<Ordered<u8>>();
+    }
+
+    #[test]
+    fn ordered_serialization() {
+        let () = check_serialization::<Ordered<u8>>();
+    }
+
+    #[test]
+    fn ordered_new_swaps() {
+        assert_eq!(Ordered::new(5_u8, 3).into_inner(), (3, 5));
+        assert_eq!(Ordered::new(3_u8, 5).into_inner(), (3, 5));
+    }
+
+    #[test]
+    fn minimal_ordered_gap() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |pair: &Ordered<u8>| {
+                    assert!(pair.lower() <= pair.upper(), "invariant violated: {pair:?}");
+                    (pair.upper().checked_sub(*pair.lower())? > 10).then_some(())
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Ordered(0, 11), ())),
+        );
+    }
+
+    #[test]
+    fn valid_range_deterministic() {
+        let () = register_globally::<ValidRange<u8>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<ValidRange<u8>> = persist::replay();
+        let () = expected.extend([
+            ValidRange(Bound::Included(6), Bound::Included(217)),
+            ValidRange(Bound::Unbounded, Bound::Unbounded),
+            ValidRange(Bound::Unbounded, Bound::Unbounded),
+            ValidRange(Bound::Unbounded, Bound::Unbounded),
+            ValidRange(Bound::Unbounded, Bound::Unbounded),
+            ValidRange(Bound::Unbounded, Bound::Unbounded),
+            ValidRange(Bound::Excluded(201), Bound::Unbounded),
+            ValidRange(Bound::Included(3), Bound::Unbounded),
+            ValidRange(Bound::Excluded(1), Bound::Unbounded),
+            ValidRange(Bound::Excluded(1), Bound::Included(93)),
+        ]);
+        let generated: Vec<ValidRange<u8>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn valid_range_enumeration_repeats_ranges() {
+        assert_eq!(cardinality::<ValidRange<bool>>(), Some(25));
+        let mut distinct: Vec<ValidRange<bool>> = vec![];
+        for range in enumerate::<ValidRange<bool>>().unwrap() {
+            if !distinct.contains(&range) {
+                let () = distinct.push(range);
+            }
+        }
+        assert_eq!(distinct.len(), 19);
+    }
+
+    #[test]
+    fn valid_range_eta_expansion() {
+        let () = check_eta_expansion::<ValidRange<u8>>();
+    }
+
+    #[test]
+    fn valid_range_serialization() {
+        let () = check_serialization::<ValidRange<u8>>();
+    }
+
+    #[test]
+    fn valid_range_new_fixes_invalid_bounds() {
+        assert_eq!(
+            ValidRange::new(Bound::Included(5_u8), Bound::Excluded(3)).into_inner(),
+            (Bound::Excluded(3), Bound::Included(5)),
+        );
+        assert_eq!(
+            ValidRange::new(Bound::Excluded(5_u8), Bound::Excluded(5)).into_inner(),
+            (Bound::Included(5), Bound::Excluded(5)),
+        );
+        assert_eq!(
+            ValidRange::new(Bound::Excluded(5_u8), Bound::Unbounded).into_inner(),
+            (Bound::Excluded(5), Bound::Unbounded),
+        );
+    }
+
+    #[test]
+    fn minimal_range_query() {
+        let set: BTreeSet<u8> = (0..10).collect();
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                // `BTreeSet::range` panics on invalid ranges,
+                // including every shrinking candidate:
+                |range: &ValidRange<u8>| (set.range(*range).count() == 3).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((ValidRange(Bound::Included(7), Bound::Unbounded), ())),
+        );
+    }
+
+    #[test]
+    fn sorted_unique_deterministic() {
+        let () = register_globally::<SortedUnique<u8>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<SortedUnique<u8>> = persist::replay();
+        let () = expected.extend(
+            [
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![0],
+                vec![60, 210],
+                vec![63],
+                vec![0],
+                vec![0, 1],
+            ]
+            .map(SortedUnique),
+        );
+        let generated: Vec<SortedUnique<u8>> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn sorted_unique_not_enumerable() {
+        assert_eq!(cardinality::<SortedUnique<bool>>(), None);
+        assert!(enumerate::<SortedUnique<bool>>().is_none());
+    }
+
+    #[test]
+    fn sorted_unique_eta_expansion() {
+        let () = check_eta_expansion::<SortedUnique<u8>>();
+    }
+
+    #[test]
+    fn sorted_unique_serialization() {
+        let () = check_serialization::<SortedUnique<u8>>();
+    }
+
+    #[test]
+    fn new_sorts_and_deduplicates() {
+        assert_eq!(SortedUnique::new(vec![3_u8, 1, 3, 2, 1]).get(), [1, 2, 3]);
+        assert!(SortedUnique::<u8>::new(vec![]).get().is_empty());
+    }
+
+    #[test]
+    fn always_strictly_increasing() {
+        let mut prng = WyRand::new(42);
+        assert!(
+            arbitrary::<SortedUnique<u8>>(&mut prng)
+                .unwrap()
+                .take(DEFAULT_N_CASES)
+                .all(|set| strictly_increasing(set.get()))
+        );
+    }
+
+    #[test]
+    fn minimal_three_elements() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |set: &SortedUnique<u8>| {
+                    assert!(
+                        strictly_increasing(set.get()),
+                        "invariant violated: {set:?}"
+                    );
+                    (set.get().len() >= 3).then_some(())
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((SortedUnique(vec![0, 1, 2]), ())),
+        );
+    }
+}
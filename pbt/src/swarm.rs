@@ -5,7 +5,7 @@ use {
     crate::{
         Pbt, fields,
         hash::map,
-        instantiability,
+        instantiability, modulo,
         multiset::Multiset,
         reflection::{Constructor, Constructors, Erased, Parts, Uninstantiable, constructors_of},
         scc,
@@ -112,13 +112,8 @@ impl Swarm {
                 let n = NonZero::new(generators.len()).expect(
                     "INTERNAL ERROR (`pbt`): swarm created for an uninstantiable literal type",
                 );
-                #[expect(
-                    clippy::as_conversions,
-                    clippy::cast_possible_truncation,
-                    reason = "Intentional lossy sampling from a 64-bit PRNG into machine-word indices."
-                )]
-                let generator_index = prng.rand() as usize % n;
-                // SAFETY: `%` above.
+                let generator_index = modulo::below(prng, n);
+                // SAFETY: `below` above.
                 let erased = unsafe { *generators.get_unchecked(generator_index) };
                 // SAFETY: `Registration::register::<T>` erased this function pointer.
                 let generate = unsafe {
@@ -138,13 +133,8 @@ impl Swarm {
             panic!("INTERNAL ERROR (`pbt`): swarm created for an uninstantiable type")
         };
 
-        #[expect(
-            clippy::as_conversions,
-            clippy::cast_possible_truncation,
-            reason = "OK: `u64` is already huge"
-        )]
-        let ctor_index = prng.rand() as usize % n;
-        // SAFETY: `%` above.
+        let ctor_index = modulo::below(prng, n);
+        // SAFETY: `below` above.
         let ctor = unsafe { ctors.get_unchecked(ctor_index) };
 
         let n_ind = self.count_inductive_fields(ctor.field_types());
@@ -293,11 +283,6 @@ impl Swarm {
 /// This works better than enabling each individually,
 /// since binomial distributions collapse very quickly.
 #[inline]
-#[expect(
-    clippy::as_conversions,
-    clippy::cast_possible_truncation,
-    reason = "OK: `u64` is already huge"
-)]
 #[expect(
     clippy::arithmetic_side_effects,
     reason = "if there were more than `usize::MAX` fields, they wouldn't have compiled"
@@ -314,8 +299,8 @@ fn n_features_to_mask(n_total: usize, prng: &mut WyRand) -> usize {
     // etc., up to "all n features" with weight (n+1).
     'rejection_sampling: loop {
         // Sample two points in an `inclusive` by `inclusive` matrix:
-        let y = prng.rand() as usize % inclusive;
-        let x = prng.rand() as usize % inclusive;
+        let y = modulo::below(prng, inclusive);
+        let x = modulo::below(prng, inclusive);
 
         // Reject above the diagonal:
         if x > y {
@@ -332,20 +317,17 @@ fn n_features_to_mask(n_total: usize, prng: &mut WyRand) -> usize {
 
 /// Pseudorandomly choose which of `n` features remain enabled.
 #[inline]
-#[expect(
-    clippy::arithmetic_side_effects,
-    clippy::as_conversions,
-    clippy::cast_possible_truncation,
-    clippy::integer_division_remainder_used,
-    reason = "OK: `u64` is already huge"
-)]
 fn mask_for(n_total: usize, prng: &mut WyRand) -> Vec<bool> {
     let n_to_mask = n_features_to_mask(n_total, prng);
     let mut mask = vec![true; n_total];
+    // Masking at least one feature implies at least one feature:
+    let Some(nonzero_total) = NonZero::new(n_total) else {
+        return mask;
+    };
     for _ in 0..n_to_mask {
         'rejection_sampling: loop {
-            let i = prng.rand() as usize % n_total;
-            // SAFETY: `%` above
+            let i = modulo::below(prng, nonzero_total);
+            // SAFETY: `below` above
             let flip = unsafe { mask.get_unchecked_mut(i) };
             if *flip {
                 *flip = false;
@@ -0,0 +1,240 @@
+//! Lookup tables over every input of a few bits, e.g. truth tables.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+/// A function from `BITS`-bit inputs to `T`, stored as one entry per input.
+///
+/// Generation chooses between two cases:
+/// one value copied into every entry, or an independent value for each entry.
+/// Shrinking tries a constant table before shrinking individual entries.
+///
+/// Every constant table can be built either way, so `cardinality` and `enumerate`
+/// count it twice: e.g. `cardinality::<Table<bool, 1>>()` is `Some(6)`, not `Some(4)`,
+/// and `check_enumeration` rejects this type.
+///
+/// Registering a table records one field type per entry, i.e. `2^BITS` `TypeId`s,
+/// so this is meant for small `BITS`.
+///
+/// A table too large to index is a compile-time error:
+/// ```compile_fail
+/// let _ = pbt::tables::Table::<bool, 128>::new(vec![]);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Table<T, const BITS: u32>(Vec<T>);
+
+impl<T, const BITS: u32> Table<T, BITS> {
+    /// The number of entries, i.e. `2^BITS`,
+    /// rejecting tables too large to index as soon as this type is used.
+    pub const LEN: usize = match 1_usize.checked_shl(BITS) {
+        Some(len) => len,
+        None => panic!("`Table<_, BITS>` requires `2^BITS` to fit in a `usize`"),
+    };
+
+    /// Every entry, in order of input.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[T] {
+        &self.0
+    }
+
+    /// The entry for this input, if the input has at most `BITS` bits.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, input: usize) -> Option<&T> {
+        self.0.get(input)
+    }
+
+    /// Unwrap the underlying entries.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Wrap entries if and only if there's exactly one for each input.
+    #[inline]
+    #[must_use]
+    pub fn new(entries: Vec<T>) -> Option<Self> {
+        (entries.len() == Self::LEN).then_some(Self(entries))
+    }
+}
+
+impl<T, const BITS: u32> Pbt for Table<T, BITS>
+where
+    T: PartialEq + Pbt,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`Table` is not a literal").get();
+        match algebraic_index {
+            1 => Self(iter::repeat_n(fields.field(), Self::LEN).collect()),
+            2 => Self(
+                iter::repeat_with(|| fields.field())
+                    .take(Self::LEN)
+                    .collect(),
+            ),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `Table`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let Self(entries) = self;
+        let variant_index = if let Some(first) = entries.first()
+            && entries.iter().all(|entry| entry == first)
+        {
+            let () = entries
+                .into_iter()
+                .take(1)
+                .for_each(|entry| fields.push(entry));
+            const { NonZero::new(1).unwrap() }
+        } else {
+            // Fields are popped in reverse:
+            for entry in entries.into_iter().rev() {
+                let () = fields.push(entry);
+            }
+            const { NonZero::new(2).unwrap() }
+        };
+        Parts {
+            fields,
+            variant_index: Some(variant_index),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<T>();
+        Variants::Algebraic(vec![
+            Variant {
+                field_types: iter::once(TypeId::of::<T>()).collect(),
+            },
+            Variant {
+                field_types: iter::repeat_n(TypeId::of::<T>(), Self::LEN).collect(),
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, cardinality, check_eta_expansion,
+            check_serialization, enumerate, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<[u8; 4]> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(|table: Table<u8, 2>| table.into_inner().try_into().unwrap())
+            .collect();
+        let expected: Vec<[u8; 4]> = vec![
+            [200, 6, 14, 0],
+            [14, 14, 14, 14],
+            [0, 0, 0, 0],
+            [211, 0, 232, 0],
+            [147, 8, 202, 109],
+            [147, 147, 147, 147],
+            [27, 27, 27, 27],
+            [182, 182, 182, 182],
+            [251, 129, 122, 185],
+            [36, 36, 36, 36],
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn enumeration_repeats_constant_tables() {
+        assert_eq!(cardinality::<Table<bool, 1>>(), Some(6));
+        assert_eq!(
+            enumerate::<Table<bool, 1>>().map(|tables| tables.map(Table::into_inner).collect()),
+            Some(vec![
+                vec![false, false],
+                vec![true, true],
+                vec![false, false],
+                vec![false, true],
+                vec![true, false],
+                vec![true, true],
+            ]),
+        );
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<Table<u8, 2>>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<Table<u8, 2>>();
+    }
+
+    #[test]
+    fn generates_constant_tables() {
+        let mut prng = WyRand::new(42);
+        let n_constant = arbitrary::<Table<u8, 3>>(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .filter(|table| {
+                table
+                    .entries()
+                    .iter()
+                    .all(|entry| Some(entry) == table.get(0))
+            })
+            .count();
+        assert!(
+            n_constant > DEFAULT_N_CASES >> 3_u32,
+            "only {n_constant} constant tables"
+        );
+    }
+
+    #[test]
+    fn minimal_non_monotone() {
+        // "Every boolean function of two bits is monotone" is false:
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |table: &Table<bool, 2>| {
+                    let monotone = (0..4).all(|lhs| {
+                        (0..4).all(|rhs| lhs & rhs != lhs || table.get(lhs) <= table.get(rhs))
+                    });
+                    (!monotone).then_some(())
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((Table::new(vec![false, false, true, false]).unwrap(), ())),
+        );
+    }
+}
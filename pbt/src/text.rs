@@ -0,0 +1,783 @@
+//! Restricted character classes for realistic text, e.g. identifiers,
+//! and bytes that are only sometimes valid text.
+
+use {
+    crate::{
+        Pbt,
+        coin_flips::CoinFlips,
+        fields::{Fields, Store},
+        modulo,
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, fmt::Debug, hash::Hash, iter, marker::PhantomData, num::NonZero},
+    wyrand::WyRand,
+};
+
+/// Alphanumeric ASCII characters in code-point order.
+const ALPHANUMERIC: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Characters likely to trip up text-handling code.
+const COMMON_ASCII: &[u8; 4] = b"aZ0 ";
+
+/// Bytes that are never valid UTF-8 on their own:
+/// lone continuation bytes, truncated leads, and bytes that never appear at all.
+const INVALID_UTF8: &[u8; 6] = &[0x80, 0xBF, 0xC3, 0xE2, 0xF0, 0xFF];
+
+/// Byte sequences likely to trip up decoders.
+const MAYBE_UTF8_EDGE_CASES: [&[u8]; 4] = [
+    b"",
+    "\u{e9}\u{20ac}\u{1d11e}".as_bytes(),
+    &[0xFF],
+    &[0xE2, 0x82],
+];
+
+/// One character of each encoded length, from one to four bytes.
+const MULTIBYTE_CHARS: [char; 4] = ['a', '\u{e9}', '\u{20ac}', '\u{1d11e}'];
+
+/// A fixed set of characters, e.g. the digits of some base or a grammar's terminals.
+///
+/// Implement this on a unit struct, which then parameterizes
+/// [`Letter`] and [`StringOf`]:
+/// ```
+/// #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// struct Binary;
+///
+/// impl pbt::text::Alphabet for Binary {
+///     const CHARS: &'static str = "01";
+/// }
+///
+/// assert!(pbt::text::StringOf::<Binary>::new("0110".to_owned()).is_some());
+/// assert!(pbt::text::StringOf::<Binary>::new("012".to_owned()).is_none());
+/// ```
+pub trait Alphabet: 'static + Copy + Debug + Eq + Hash + Ord {
+    /// Every character in this alphabet, from simplest to most complex.
+    ///
+    /// Values shrink toward the first character.
+    /// Must be nonempty, and each character should appear only once
+    /// (duplicates are simply generated more often).
+    const CHARS: &'static str;
+}
+
+/// A single character from an [`Alphabet`].
+///
+/// Shrinks toward the alphabet's first character.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Letter<A>(char, PhantomData<A>)
+where
+    A: Alphabet;
+
+/// A string containing only characters from an [`Alphabet`].
+///
+/// Generation and shrinking both operate on an ordinary `Vec<Letter<A>>`,
+/// so empty and single-character strings come up early,
+/// and shrinking removes characters (and shrinks those that remain).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StringOf<A>(String, PhantomData<A>)
+where
+    A: Alphabet;
+
+/// An ASCII character (`'\0'..='\x7F'`).
+///
+/// Shrinks toward `'\0'`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ascii(char);
+
+/// An alphanumeric ASCII character (`'0'..='9'`, `'A'..='Z'`, or `'a'..='z'`).
+///
+/// Shrinks toward `'0'` by halving its index in `0-9A-Za-z`,
+/// so e.g. `'z'` shrinks to `'0'` first, then to `'V'`, `'k'`, and so on.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Alphanumeric(char);
+
+/// Bytes that are sometimes valid UTF-8, e.g. to test `str::from_utf8` or lossy decoders.
+///
+/// Generation either encodes a string mixing one- through four-byte characters,
+/// or emits raw bytes seeded with lone continuation bytes and truncated multibyte leads,
+/// or chooses an edge case: empty, a valid multibyte string,
+/// `[0xFF]`, or a truncated `'\u{20ac}'`.
+/// Shrinking removes bytes, then moves each remaining byte toward `0`.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "every byte sequence is a valid `MaybeUtf8`"
+)]
+pub struct MaybeUtf8(pub Vec<u8>);
+
+impl Ascii {
+    /// The underlying character.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> char {
+        self.0
+    }
+
+    /// Wrap a character if and only if it's ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn new(c: char) -> Option<Self> {
+        if c.is_ascii() { Some(Self(c)) } else { None }
+    }
+}
+
+impl Alphanumeric {
+    /// The underlying character.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> char {
+        self.0
+    }
+
+    /// Wrap a character if and only if it's alphanumeric ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn new(c: char) -> Option<Self> {
+        if c.is_ascii_alphanumeric() {
+            Some(Self(c))
+        } else {
+            None
+        }
+    }
+}
+
+impl<A> Letter<A>
+where
+    A: Alphabet,
+{
+    /// Rejects empty alphabets as soon as this type is used.
+    const NONEMPTY: () = assert!(!A::CHARS.is_empty(), "an `Alphabet` must be nonempty");
+
+    /// The underlying character.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> char {
+        self.0
+    }
+
+    /// Wrap a character if and only if it's in the alphabet.
+    #[inline]
+    #[must_use]
+    pub fn new(c: char) -> Option<Self> {
+        let () = Self::NONEMPTY;
+        A::CHARS.contains(c).then_some(Self(c, PhantomData))
+    }
+
+    /// The character at this index in the alphabet, if any.
+    #[inline]
+    fn nth(index: usize) -> Option<Self> {
+        A::CHARS.chars().nth(index).map(|c| Self(c, PhantomData))
+    }
+}
+
+impl<A> StringOf<A>
+where
+    A: Alphabet,
+{
+    /// The underlying string.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap the underlying string.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Wrap a string if and only if every character is in the alphabet.
+    #[inline]
+    #[must_use]
+    pub fn new(s: String) -> Option<Self> {
+        s.chars()
+            .all(|c| A::CHARS.contains(c))
+            .then_some(Self(s, PhantomData))
+    }
+}
+
+impl<A> Pbt for Letter<A>
+where
+    A: Alphabet,
+{
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Letter` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = Self::NONEMPTY;
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                Self::new(s.parse().ok()?)
+            },
+            generators: vec![
+                #[expect(
+                    clippy::expect_used,
+                    reason = "Internal invariants: violations should fail loudly."
+                )]
+                |prng| {
+                    let n_chars = NonZero::new(A::CHARS.chars().count())
+                        .expect("INTERNAL ERROR (`pbt`): empty alphabet");
+                    Self::nth(modulo::below(prng, n_chars))
+                        .expect("INTERNAL ERROR (`pbt`): alphabet index out of range")
+                },
+            ],
+            serialize: |&Self(c, PhantomData)| c.to_string().into(),
+            shrink: |Self(c, PhantomData)| {
+                let index = A::CHARS.chars().position(|other| other == c).unwrap_or(0);
+                Box::new((shrink!(usize))(index).filter_map(Self::nth))
+            },
+        }
+    }
+}
+
+impl<A> Pbt for StringOf<A>
+where
+    A: Alphabet,
+{
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`StringOf` is not a literal").get();
+        match algebraic_index {
+            1 => {
+                let letters: Vec<Letter<A>> = fields.field();
+                Self(letters.into_iter().map(Letter::get).collect(), PhantomData)
+            }
+            _ => panic!("can't instantiate variant #{algebraic_index} of `StringOf`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () =
+            fields.push::<Vec<Letter<A>>>(self.0.chars().map(|c| Letter(c, PhantomData)).collect());
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<Vec<Letter<A>>>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<Vec<Letter<A>>>()).collect(),
+        }])
+    }
+}
+
+impl Pbt for Ascii {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Ascii` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                Self::new(s.parse().ok()?)
+            },
+            generators: vec![
+                |prng| Self(char::from(modulo::pick(prng, COMMON_ASCII))),
+                #[expect(
+                    clippy::as_conversions,
+                    clippy::cast_possible_truncation,
+                    reason = "intentional: keep only the low bits"
+                )]
+                |prng| Self(char::from(prng.rand() as u8 & 0x7F)),
+            ],
+            serialize: |&Self(c)| c.to_string().into(),
+            shrink: |Self(c)| {
+                Box::new(
+                    (shrink!(u32))(u32::from(c))
+                        .filter_map(char::from_u32)
+                        .map(Self),
+                )
+            },
+        }
+    }
+}
+
+impl Pbt for Alphanumeric {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`Alphanumeric` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::String(ref s) = *json else {
+                    return None;
+                };
+                Self::new(s.parse().ok()?)
+            },
+            generators: vec![|prng| Self(char::from(modulo::pick(prng, ALPHANUMERIC)))],
+            serialize: |&Self(c)| c.to_string().into(),
+            shrink: |Self(c)| {
+                let index = ALPHANUMERIC
+                    .iter()
+                    .position(|&byte| char::from(byte) == c)
+                    .unwrap_or(0);
+                Box::new((shrink!(usize))(index).filter_map(|shrunk| {
+                    let &byte = ALPHANUMERIC.get(shrunk)?;
+                    Some(Self(char::from(byte)))
+                }))
+            },
+        }
+    }
+}
+
+impl Pbt for MaybeUtf8 {
+    #[inline]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        debug_assert_eq!(variant_index, None, "`MaybeUtf8` is a literal");
+        fields.field()
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self);
+        Parts {
+            fields,
+            variant_index: None,
+        }
+    }
+
+    #[inline]
+    fn register(_registration: &mut Registration<'_>) -> Variants<Self> {
+        Variants::Literal {
+            deserialize: |json| {
+                let serde_json::Value::Array(ref json_bytes) = *json else {
+                    return None;
+                };
+                json_bytes
+                    .iter()
+                    .map(|byte| u8::try_from(byte.as_u64()?).ok())
+                    .collect::<Option<_>>()
+                    .map(Self)
+            },
+            generators: vec![valid_utf8, mostly_invalid_utf8, |prng| {
+                Self(modulo::pick(prng, &MAYBE_UTF8_EDGE_CASES).to_vec())
+            }],
+            serialize: |&Self(ref bytes)| bytes.iter().copied().collect(),
+            shrink: shrink_maybe_utf8,
+        }
+    }
+}
+
+/// Generate raw bytes, roughly half of which can't appear in valid UTF-8 on their own.
+#[inline]
+fn mostly_invalid_utf8(prng: &mut WyRand) -> MaybeUtf8 {
+    let mut coin = CoinFlips::new(prng);
+    let mut bytes = vec![];
+    while coin.flip(prng) {
+        let byte = if coin.flip(prng) {
+            modulo::pick(prng, INVALID_UTF8)
+        } else {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "intentional: keep only the low bits"
+            )]
+            let byte = prng.rand() as u8;
+            byte
+        };
+        let () = bytes.push(byte);
+    }
+    MaybeUtf8(bytes)
+}
+
+/// Remove all bytes, then each byte, then shrink each remaining byte toward `0`.
+#[inline]
+fn shrink_maybe_utf8(MaybeUtf8(bytes): MaybeUtf8) -> Box<dyn Iterator<Item = MaybeUtf8>> {
+    let nonempty = !bytes.is_empty();
+    let removed = {
+        let original = bytes.clone();
+        (0..original.len()).map(move |i| {
+            let mut removed = original.clone();
+            let _: u8 = removed.remove(i);
+            removed
+        })
+    };
+    let lowered = (0..bytes.len()).flat_map(move |i| {
+        let original = bytes.clone();
+        let byte = original.get(i).copied().unwrap_or(0);
+        (shrink!(u8))(byte).filter_map(move |shrunk| {
+            let mut lowered = original.clone();
+            *lowered.get_mut(i)? = shrunk;
+            Some(lowered)
+        })
+    });
+    Box::new(
+        iter::once(vec![])
+            .filter(move |_| nonempty)
+            .chain(removed)
+            .chain(lowered)
+            .map(MaybeUtf8),
+    )
+}
+
+/// Encode a short string mixing characters of every encoded length.
+#[inline]
+fn valid_utf8(prng: &mut WyRand) -> MaybeUtf8 {
+    let mut coin = CoinFlips::new(prng);
+    let mut s = String::new();
+    while coin.flip(prng) {
+        let () = s.push(modulo::pick(prng, &MULTIBYTE_CHARS));
+    }
+    MaybeUtf8(s.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            persist, reflection::register_globally, witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    /// The standard base-64 alphabet (RFC 4648), without padding.
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Base64;
+
+    impl Alphabet for Base64 {
+        const CHARS: &'static str =
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    }
+
+    #[test]
+    fn deterministic_ascii() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<char> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(Ascii::get)
+            .collect();
+        let expected: Vec<char> = vec!['j', 'N', '\u{e}', '0', 'a', 'a', 'F', 'e', 'H', 'i'];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn deterministic_alphanumeric() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<char> = arbitrary(&mut prng)
+            .unwrap()
+            .take(10)
+            .map(Alphanumeric::get)
+            .collect();
+        let expected: Vec<char> = vec!['K', 'K', 'r', 'V', 'r', 'y', 'E', 'X', 'y', 'T'];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn ascii_eta_expansion() {
+        let () = check_eta_expansion::<Ascii>();
+    }
+
+    #[test]
+    fn ascii_serialization() {
+        let () = check_serialization::<Ascii>();
+    }
+
+    #[test]
+    fn alphanumeric_eta_expansion() {
+        let () = check_eta_expansion::<Alphanumeric>();
+    }
+
+    #[test]
+    fn alphanumeric_serialization() {
+        let () = check_serialization::<Alphanumeric>();
+    }
+
+    #[test]
+    fn ascii_is_ascii() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |c: &Ascii| (c.get() > '\x7F').then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn alphanumeric_identifiers() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |s: &Vec<Alphanumeric>| {
+                    let identifier: String = s.iter().map(|c| c.get()).collect();
+                    identifier.contains('z').then_some(identifier)
+                },
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((vec![Alphanumeric('z')], "z".to_owned())),
+        );
+    }
+
+    #[test]
+    fn string_of_deterministic() {
+        let () = register_globally::<StringOf<Base64>>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<String> = persist::replay::<StringOf<Base64>>()
+            .into_iter()
+            .map(StringOf::into_inner)
+            .collect();
+        let () = expected.extend(
+            ["", "", "", "", "", "C", "", "", "", ""]
+                .into_iter()
+                .map(str::to_owned),
+        );
+        let generated: Vec<String> = arbitrary(&mut prng)
+            .unwrap()
+            .take(expected.len())
+            .map(StringOf::<Base64>::into_inner)
+            .collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn letter_eta_expansion() {
+        let () = check_eta_expansion::<Letter<Base64>>();
+    }
+
+    #[test]
+    fn letter_serialization() {
+        let () = check_serialization::<Letter<Base64>>();
+    }
+
+    #[test]
+    fn string_of_eta_expansion() {
+        let () = check_eta_expansion::<StringOf<Base64>>();
+    }
+
+    #[test]
+    fn string_of_serialization() {
+        let () = check_serialization::<StringOf<Base64>>();
+    }
+
+    #[test]
+    fn string_of_stays_in_alphabet() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |s: &StringOf<Base64>| s
+                    .get()
+                    .chars()
+                    .any(|c| !Base64::CHARS.contains(c))
+                    .then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn minimal_base64_with_both_symbols() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |s: &StringOf<Base64>| (s.get().contains('+') && s.get().contains('/'))
+                    .then(|| s.get().len()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((StringOf::new("/+".to_owned()).unwrap(), 2)),
+        );
+    }
+
+    #[test]
+    fn maybe_utf8_deterministic() {
+        let () = register_globally::<MaybeUtf8>();
+        let mut prng = WyRand::new(42);
+        let mut expected: Vec<MaybeUtf8> = persist::replay();
+        let () = expected.extend(
+            [
+                vec![0xF0, 0xF0],
+                "\u{20ac}".as_bytes().to_vec(),
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![0x0B, 0xC8],
+            ]
+            .map(MaybeUtf8),
+        );
+        let generated: Vec<MaybeUtf8> =
+            arbitrary(&mut prng).unwrap().take(expected.len()).collect();
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn maybe_utf8_deterministic_shrink() {
+        let shrunk: Vec<Vec<u8>> = shrink_maybe_utf8(MaybeUtf8(vec![0xC3, 0x29]))
+            .map(|MaybeUtf8(bytes)| bytes)
+            .collect();
+        let expected: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0x29],
+            vec![0xC3],
+            vec![0x00, 0x29],
+            vec![0x62, 0x29],
+            vec![0x93, 0x29],
+            vec![0xAB, 0x29],
+            vec![0xB7, 0x29],
+            vec![0xBD, 0x29],
+            vec![0xC0, 0x29],
+            vec![0xC2, 0x29],
+            vec![0xC3, 0x00],
+            vec![0xC3, 0x15],
+            vec![0xC3, 0x1F],
+            vec![0xC3, 0x24],
+            vec![0xC3, 0x27],
+            vec![0xC3, 0x28],
+        ];
+        assert_eq!(shrunk, expected);
+    }
+
+    #[test]
+    fn maybe_utf8_eta_expansion() {
+        let () = check_eta_expansion::<MaybeUtf8>();
+    }
+
+    #[test]
+    fn maybe_utf8_serialization() {
+        let () = check_serialization::<MaybeUtf8>();
+    }
+
+    #[test]
+    fn maybe_utf8_generates_valid_and_invalid() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<MaybeUtf8> = arbitrary(&mut prng)
+            .unwrap()
+            .take(DEFAULT_N_CASES)
+            .collect();
+        assert!(generated.iter().any(|&MaybeUtf8(ref bytes)| {
+            str::from_utf8(bytes).is_ok_and(|s| s.len() > s.chars().count())
+        }));
+        assert!(
+            generated
+                .iter()
+                .any(|&MaybeUtf8(ref bytes)| str::from_utf8(bytes).is_err())
+        );
+    }
+
+    #[test]
+    fn minimal_invalid_utf8() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |&MaybeUtf8(ref bytes): &MaybeUtf8| str::from_utf8(bytes).err(),
+                DEFAULT_N_CASES,
+                &mut prng,
+            )
+            .map(|(MaybeUtf8(bytes), _)| bytes),
+            Some(vec![0x80]),
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Reproducible stand-ins for wall-clock time.
+
+use {
+    crate::{
+        Pbt,
+        fields::{Fields, Store},
+        reflection::{Parts, Variant, Variants},
+        registration::Registration,
+    },
+    core::{any::TypeId, iter, num::NonZero},
+};
+
+/// A logical timestamp, e.g. a Lamport clock or a tick counter.
+///
+/// Generation draws from the same distributions as `u64`,
+/// so small counters (`0`, `1`, ...) are common
+/// but the full range (up to `u64::MAX`) is reachable.
+/// Shrinking moves toward `LogicalTime(0)`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "a transparent newtype meant to be constructed directly"
+)]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`LogicalTime` reads better than `time::Logical`"
+)]
+pub struct LogicalTime(pub u64);
+
+impl Pbt for LogicalTime {
+    #[inline]
+    #[expect(
+        clippy::expect_used,
+        clippy::panic,
+        reason = "end-users shouldn't be calling this"
+    )]
+    fn construct<F>(
+        Parts {
+            mut fields,
+            variant_index,
+        }: Parts<F>,
+    ) -> Self
+    where
+        F: Fields,
+    {
+        let algebraic_index: usize = variant_index.expect("`LogicalTime` is not a literal").get();
+        match algebraic_index {
+            1 => Self(fields.field()),
+            _ => panic!("can't instantiate variant #{algebraic_index} of `LogicalTime`"),
+        }
+    }
+
+    #[inline]
+    fn deconstruct(self) -> Parts<Store> {
+        let mut fields = Store::new();
+        let () = fields.push(self.0);
+        Parts {
+            fields,
+            variant_index: Some(const { NonZero::new(1).unwrap() }),
+        }
+    }
+
+    #[inline]
+    fn register(registration: &mut Registration<'_>) -> Variants<Self> {
+        let () = registration.register::<u64>();
+        Variants::Algebraic(vec![Variant {
+            field_types: iter::once(TypeId::of::<u64>()).collect(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "failing tests ought to panic")]
+
+    use {
+        super::*,
+        crate::{
+            DEFAULT_N_CASES, arbitrary::arbitrary, check_eta_expansion, check_serialization,
+            witness,
+        },
+        pretty_assertions::assert_eq,
+        wyrand::WyRand,
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut prng = WyRand::new(42);
+        let generated: Vec<LogicalTime> = arbitrary(&mut prng).unwrap().take(10).collect();
+        let expected: Vec<LogicalTime> = vec![
+            LogicalTime(0),
+            LogicalTime(7_804_948_724_862_110_416),
+            LogicalTime(17_108_568_891_541_767_080),
+            LogicalTime(14_756_591_828_928_955_088),
+            LogicalTime(1),
+            LogicalTime(1),
+            LogicalTime(10),
+            LogicalTime(19),
+            LogicalTime(13),
+            LogicalTime(0),
+        ];
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn eta_expansion() {
+        let () = check_eta_expansion::<LogicalTime>();
+    }
+
+    #[test]
+    fn serialization() {
+        let () = check_serialization::<LogicalTime>();
+    }
+
+    #[test]
+    fn minimal_non_monotonic() {
+        let mut prng = WyRand::new(42);
+        assert_eq!(
+            witness(
+                |timestamps: &Vec<LogicalTime>| (!timestamps.is_sorted()).then_some(()),
+                DEFAULT_N_CASES,
+                &mut prng,
+            ),
+            Some((vec![LogicalTime(1), LogicalTime(0)], ())),
+        );
+    }
+}